@@ -0,0 +1,199 @@
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use aoc2025::parse::ParseError;
+
+use crate::days::Day;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+// Timings for one phase (parse, part1 or part2) of one day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timing {
+    pub day: u32,
+    pub phase: &'static str,
+    pub runs: usize,
+    pub stats: Stats,
+}
+
+// Times each phase `runs` times after `warmup` untimed runs. The parts are
+// timed on a single parsed input.
+pub fn bench_day(day: &Day, input: &str, runs: usize, warmup: usize) -> Result<Vec<Timing>, ParseError> {
+    let parsed = day.parse(input)?;
+
+    let mut timings = vec![Timing {
+        day: day.number,
+        phase: "parse",
+        runs,
+        stats: measure(runs, warmup, || {
+            black_box(day.parse(black_box(input)).ok());
+        }),
+    }];
+    for (part, phase) in [(1, "part1"), (2, "part2")] {
+        timings.push(Timing {
+            day: day.number,
+            phase,
+            runs,
+            stats: measure(runs, warmup, || {
+                let _ = black_box(day.solve(black_box(parsed.as_ref()), part));
+            }),
+        });
+    }
+    Ok(timings)
+}
+
+fn measure(runs: usize, warmup: usize, mut f: impl FnMut()) -> Stats {
+    for _ in 0..warmup {
+        f();
+    }
+    let mut samples: Vec<Duration> = (0..runs)
+        .map(|_| {
+            let start = Instant::now();
+            f();
+            start.elapsed()
+        })
+        .collect();
+    stats(&mut samples)
+}
+
+// Nearest-rank percentiles over the samples, which must not be empty.
+pub fn stats(samples: &mut [Duration]) -> Stats {
+    samples.sort();
+    let rank = |p: f64| samples[((p * samples.len() as f64).ceil() as usize).clamp(1, samples.len()) - 1];
+    Stats {
+        min: samples[0],
+        median: rank(0.5),
+        p95: rank(0.95),
+    }
+}
+
+pub fn format_duration(d: Duration) -> String {
+    let nanos = d.as_nanos();
+    if nanos < 1_000 {
+        format!("{}ns", nanos)
+    } else if nanos < 1_000_000 {
+        format!("{:.1}µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.1}ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2}s", nanos as f64 / 1e9)
+    }
+}
+
+pub fn to_table(timings: &[Timing]) -> String {
+    let mut out = format!("{:>3}  {:<6} {:>5} {:>10} {:>10} {:>10}\n", "day", "phase", "runs", "min", "median", "p95");
+    for t in timings {
+        out += &format!(
+            "{:>3}  {:<6} {:>5} {:>10} {:>10} {:>10}\n",
+            t.day,
+            t.phase,
+            t.runs,
+            format_duration(t.stats.min),
+            format_duration(t.stats.median),
+            format_duration(t.stats.p95)
+        );
+    }
+    out
+}
+
+// Machine-readable forms use integer nanoseconds.
+pub fn to_csv(timings: &[Timing]) -> String {
+    let mut out = "day,phase,runs,min_ns,median_ns,p95_ns\n".to_string();
+    for t in timings {
+        out += &format!(
+            "{},{},{},{},{},{}\n",
+            t.day,
+            t.phase,
+            t.runs,
+            t.stats.min.as_nanos(),
+            t.stats.median.as_nanos(),
+            t.stats.p95.as_nanos()
+        );
+    }
+    out
+}
+
+pub fn to_json(timings: &[Timing]) -> String {
+    let rows: Vec<String> = timings
+        .iter()
+        .map(|t| {
+            format!(
+                "  {{\"day\": {}, \"phase\": \"{}\", \"runs\": {}, \"min_ns\": {}, \"median_ns\": {}, \"p95_ns\": {}}}",
+                t.day,
+                t.phase,
+                t.runs,
+                t.stats.min.as_nanos(),
+                t.stats.median.as_nanos(),
+                t.stats.p95.as_nanos()
+            )
+        })
+        .collect();
+    format!("[\n{}\n]\n", rows.join(",\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    #[test]
+    fn test_stats() {
+        let mut samples: Vec<Duration> = (1..=20).rev().map(ms).collect();
+        assert_eq!(
+            stats(&mut samples),
+            Stats {
+                min: ms(1),
+                median: ms(10),
+                p95: ms(19)
+            }
+        );
+        assert_eq!(stats(&mut [ms(7)]).p95, ms(7));
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_nanos(999)), "999ns");
+        assert_eq!(format_duration(Duration::from_nanos(12_345)), "12.3µs");
+        assert_eq!(format_duration(ms(250)), "250.0ms");
+        assert_eq!(format_duration(ms(1500)), "1.50s");
+    }
+
+    #[test]
+    fn test_output_formats() {
+        let timings = [Timing {
+            day: 8,
+            phase: "part1",
+            runs: 3,
+            stats: Stats {
+                min: ms(1),
+                median: ms(2),
+                p95: ms(3),
+            },
+        }];
+        assert_eq!(
+            to_csv(&timings),
+            "day,phase,runs,min_ns,median_ns,p95_ns\n8,part1,3,1000000,2000000,3000000\n"
+        );
+        assert_eq!(
+            to_json(&timings),
+            "[\n  {\"day\": 8, \"phase\": \"part1\", \"runs\": 3, \"min_ns\": 1000000, \"median_ns\": 2000000, \"p95_ns\": 3000000}\n]\n"
+        );
+    }
+
+    #[test]
+    fn test_bench_day() {
+        let day = crate::days::find(1).unwrap();
+        let timings = bench_day(day, "L68\nL30\n", 3, 1).unwrap();
+        let phases: Vec<&str> = timings.iter().map(|t| t.phase).collect();
+        assert_eq!(phases, ["parse", "part1", "part2"]);
+        assert!(bench_day(day, "X1\n", 3, 1).is_err());
+    }
+}
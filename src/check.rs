@@ -0,0 +1,89 @@
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::days::Day;
+
+// One part run on an input whose answer is (maybe) known.
+pub struct Check {
+    pub day: u32,
+    pub part: u8,
+    pub expected: Option<String>,
+    pub actual: Result<String, String>,
+}
+
+impl Check {
+    pub fn passed(&self) -> bool {
+        match (&self.expected, &self.actual) {
+            (Some(expected), Ok(actual)) => expected == actual,
+            _ => false,
+        }
+    }
+
+    // The answer, or the error that replaced it.
+    pub fn outcome(&self) -> &str {
+        match &self.actual {
+            Ok(answer) => answer,
+            Err(e) => e,
+        }
+    }
+}
+
+// Runs both parts of `day` on `input` (named `source` in parse errors).
+// Parse errors, parts without an answer and panics become failed checks
+// rather than aborting.
+pub fn check(day: &Day, input: &str, source: &str, expected: [Option<String>; 2]) -> Vec<Check> {
+    let parsed = day.parse(input).map_err(|e| e.in_file(source).to_string());
+
+    let mut checks = Vec::new();
+    for (part, expected) in (1..=2).zip(expected) {
+        let actual = match &parsed {
+            Ok(input) => catch_panic(|| day.solve(input.as_ref(), part)).and_then(|answer| answer.map(|a| a.to_string())),
+            Err(e) => Err(e.clone()),
+        };
+        checks.push(Check {
+            day: day.number,
+            part,
+            expected,
+            actual,
+        });
+    }
+    checks
+}
+
+// Runs `f`, turning a panic into an error carrying the panic message.
+pub fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(f)).map_err(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown panic".to_string());
+        format!("panicked: {}", message)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        let day = crate::days::find(1).unwrap();
+        let checks = check(day, "L68\nL30\nR48\n", "test", [Some("1".to_string()), None]);
+        assert!(checks[0].passed());
+        assert!(!checks[1].passed());
+        assert_eq!(checks[1].outcome(), "2");
+
+        let checks = check(day, "L68\nX30\n", "test", [None, None]);
+        assert_eq!(checks[0].outcome(), "test:2:1: expected direction L or R");
+
+        let day = crate::days::find(6).unwrap();
+        let checks = check(day, "9999999999 9999999999\n9999999999 9999999999\n*          *", "test", [None, None]);
+        assert_eq!(checks[0].actual, Err("the grand total overflows 64 bits".to_string()));
+    }
+
+    #[test]
+    fn test_catch_panic() {
+        assert_eq!(catch_panic(|| 42), Ok(42));
+        assert_eq!(catch_panic(|| -> i32 { panic!("boom") }), Err("panicked: boom".to_string()));
+    }
+}
@@ -1,22 +1,49 @@
-fn main() {
-    let str = std::fs::read_to_string("data/01.txt").expect("Failed to read input file");
-    let input = parse(str);
-    println!("part1: {}", part1(&input));
-    println!("part2: {}", part2(&input));
+use crate::parse::{self, ParseError};
+use crate::solution::{Answer, Solution};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<(char, i32)>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, String> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, String> {
+        Ok(part2(input).into())
+    }
 }
 
-fn parse(input: String) -> Vec<(char, i32)> {
+pub fn parse(input: &str) -> Result<Vec<(char, i32)>, ParseError> {
     input
         .lines()
         .map(|line| {
-            let dir = line.chars().next().unwrap();
-            let dist: i32 = line[1..].parse().unwrap();
-            (dir, dist)
+            let dir = match line.chars().next() {
+                Some(dir @ ('L' | 'R')) => dir,
+                _ => return Err(ParseError::at(input, line, "direction L or R")),
+            };
+            Ok((dir, distance(input, &line[1..])?))
         })
         .collect()
 }
 
-fn part1(input: &[(char, i32)]) -> i32 {
+// Positions are i32 and a rotation is added to one, so huge ones are refused
+fn distance(input: &str, s: &str) -> Result<i32, ParseError> {
+    let dist = parse::number(input, s, "distance")?;
+    if !(0..=MAX_DISTANCE).contains(&dist) {
+        return Err(ParseError::at(input, s, format!("distance from 0 to {}", MAX_DISTANCE)));
+    }
+    Ok(dist)
+}
+
+const MAX_DISTANCE: i32 = 1_000_000;
+
+pub fn part1(input: &[(char, i32)]) -> i32 {
     let mut position: i32 = 50;
     let mut count = 0;
 
@@ -34,7 +61,7 @@ fn part1(input: &[(char, i32)]) -> i32 {
     count
 }
 
-fn part2(input: &[(char, i32)]) -> i32 {
+pub fn part2(input: &[(char, i32)]) -> i32 {
     let mut position: i32 = 50;
     let mut count = 0;
 
@@ -71,13 +98,21 @@ mod tests {
 
     #[test]
     fn test_part1() {
-        let input = parse("L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82".to_string());
+        let input = parse("L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82").unwrap();
         assert_eq!(part1(&input), 3);
     }
 
     #[test]
     fn test_part2() {
-        let input = parse("L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82".to_string());
+        let input = parse("L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82").unwrap();
         assert_eq!(part2(&input), 6);
     }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(parse("L68\nX30"), Err(ParseError::new(2, 1, "direction L or R")));
+        assert_eq!(parse("L68\nR3x"), Err(ParseError::new(2, 2, "distance")));
+        assert_eq!(parse("L68\nR"), Err(ParseError::new(2, 2, "distance")));
+        assert_eq!(parse("R2147483647"), Err(ParseError::new(1, 2, "distance from 0 to 1000000")));
+    }
 }
@@ -1,29 +1,60 @@
 use std::collections::HashSet;
 
-fn main() {
-    let input = std::fs::read_to_string("data/02.txt").unwrap();
-    println!("part1: {}", part1(input.trim()));
-    println!("part2: {}", part2(input.trim()));
+use crate::interval_set::IntervalSet;
+use crate::parse::{self, ParseError};
+use crate::solution::{Answer, Solution};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = IntervalSet<u64>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, String> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, String> {
+        Ok(part2(input).into())
+    }
 }
 
-fn parse(input: &str) -> Vec<(u64, u64)> {
+pub fn parse(input: &str) -> Result<IntervalSet<u64>, ParseError> {
     input
         .split(',')
+        .map(str::trim)
         .filter(|s| !s.is_empty())
         .map(|range| {
-            let parts: Vec<&str> = range.split('-').collect();
-            let start: u64 = parts[0].parse().unwrap();
-            let end: u64 = parts[1].parse().unwrap();
-            (start, end)
+            let (start, end) = range
+                .split_once('-')
+                .ok_or_else(|| ParseError::at(input, range, "range like 11-22"))?;
+            let (start, end) = (id(input, start, "range start")?, id(input, end, "range end")?);
+            // IntervalSet would drop a reversed range without a word
+            if start > end {
+                return Err(ParseError::at(input, range, "range from low to high"));
+            }
+            Ok(start..=end)
         })
         .collect()
 }
 
-fn part1(input: &str) -> u64 {
-    let ranges = parse(input);
+// The pattern search below stops at 10 digits, so longer IDs are refused
+fn id(input: &str, s: &str, expected: &str) -> Result<u64, ParseError> {
+    let id = parse::number(input, s, expected)?;
+    if id > MAX_ID {
+        return Err(ParseError::at(input, s, format!("ID of at most {} digits", MAX_ID.ilog10() + 1)));
+    }
+    Ok(id)
+}
+
+const MAX_ID: u64 = 9_999_999_999;
 
+pub fn part1(ranges: &IntervalSet<u64>) -> u64 {
     // Find max value in any range
-    let max_val = ranges.iter().map(|r| r.1).max().unwrap_or(0);
+    let max_val = ranges.max().unwrap_or(0);
 
     // Generate all repeated pattern numbers up to max_val
     let mut invalid_ids: Vec<u64> = Vec::new();
@@ -43,22 +74,11 @@ fn part1(input: &str) -> u64 {
     }
 
     // Sum all invalid IDs that appear in any range
-    let mut sum = 0u64;
-    for &id in &invalid_ids {
-        for &(start, end) in &ranges {
-            if id >= start && id <= end {
-                sum += id;
-                break; // Don't count the same ID twice
-            }
-        }
-    }
-
-    sum
+    invalid_ids.into_iter().filter(|&id| ranges.contains(id)).sum()
 }
 
-fn part2(input: &str) -> u64 {
-    let ranges = parse(input);
-    let max_val = ranges.iter().map(|r| r.1).max().unwrap_or(0);
+pub fn part2(ranges: &IntervalSet<u64>) -> u64 {
+    let max_val = ranges.max().unwrap_or(0);
 
     // Use HashSet to avoid counting duplicates (e.g., 1111 = "1"x4 or "11"x2)
     let mut invalid_ids: HashSet<u64> = HashSet::new();
@@ -91,17 +111,7 @@ fn part2(input: &str) -> u64 {
     }
 
     // Sum all invalid IDs that appear in any range
-    let mut sum = 0u64;
-    for &id in &invalid_ids {
-        for &(start, end) in &ranges {
-            if id >= start && id <= end {
-                sum += id;
-                break;
-            }
-        }
-    }
-
-    sum
+    invalid_ids.into_iter().filter(|&id| ranges.contains(id)).sum()
 }
 
 #[cfg(test)]
@@ -111,12 +121,19 @@ mod tests {
     #[test]
     fn test_part1() {
         let input = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
-        assert_eq!(part1(input), 1227775554);
+        assert_eq!(part1(&parse(input).unwrap()), 1227775554);
     }
 
     #[test]
     fn test_part2() {
         let input = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
-        assert_eq!(part2(input), 4174379265);
+        assert_eq!(part2(&parse(input).unwrap()), 4174379265);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(parse("11-22,95"), Err(ParseError::new(1, 7, "range like 11-22")));
+        assert_eq!(parse("1-99999999999"), Err(ParseError::new(1, 3, "ID of at most 10 digits")));
+        assert_eq!(parse("11-22, 22-11"), Err(ParseError::new(1, 8, "range from low to high")));
     }
 }
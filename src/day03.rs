@@ -1,11 +1,43 @@
-fn parse(input: &str) -> Vec<Vec<u32>> {
+use crate::parse::ParseError;
+use crate::solution::{Answer, Solution};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Vec<Vec<u32>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, String> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, String> {
+        Ok(part2(input).into())
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Vec<u32>>, ParseError> {
     input
         .lines()
-        .map(|line| line.chars().map(|c| c.to_digit(10).unwrap()).collect())
+        .map(|line| {
+            let bank: Vec<u32> = line
+                .char_indices()
+                .map(|(i, c)| c.to_digit(10).ok_or_else(|| ParseError::at(input, &line[i..], "digit")))
+                .collect::<Result<_, _>>()?;
+            // Part 2 turns on 12 batteries per bank
+            if bank.len() < 12 {
+                return Err(ParseError::at(input, &line[line.len()..], "a bank of at least 12 batteries"));
+            }
+            Ok(bank)
+        })
         .collect()
 }
 
-fn max_joltage(bank: &[u32]) -> u32 {
+// Largest two-digit number from two of the bank's batteries, in order
+pub fn max_joltage(bank: &[u32]) -> u32 {
     let n = bank.len();
     // Compute max suffix: max_suffix[i] = max of bank[i..n]
     let mut max_suffix = vec![0; n + 1];
@@ -21,12 +53,12 @@ fn max_joltage(bank: &[u32]) -> u32 {
     max_val
 }
 
-fn part1(input: &str) -> u32 {
-    let banks = parse(input);
+pub fn part1(banks: &[Vec<u32>]) -> u32 {
     banks.iter().map(|bank| max_joltage(bank)).sum()
 }
 
-fn max_joltage_k(bank: &[u32], k: usize) -> u64 {
+// Largest k-digit number from k of the bank's batteries, in order
+pub fn max_joltage_k(bank: &[u32], k: usize) -> u64 {
     let n = bank.len();
     let mut result = 0u64;
     let mut start = 0;
@@ -50,17 +82,10 @@ fn max_joltage_k(bank: &[u32], k: usize) -> u64 {
     result
 }
 
-fn part2(input: &str) -> u64 {
-    let banks = parse(input);
+pub fn part2(banks: &[Vec<u32>]) -> u64 {
     banks.iter().map(|bank| max_joltage_k(bank, 12)).sum()
 }
 
-fn main() {
-    let input = std::fs::read_to_string("data/03.txt").unwrap();
-    println!("part1: {}", part1(&input));
-    println!("part2: {}", part2(&input));
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -72,7 +97,7 @@ mod tests {
 
     #[test]
     fn test_part1() {
-        assert_eq!(part1(EXAMPLE), 357);
+        assert_eq!(part1(&parse(EXAMPLE).unwrap()), 357);
     }
 
     #[test]
@@ -85,7 +110,7 @@ mod tests {
 
     #[test]
     fn test_part2() {
-        assert_eq!(part2(EXAMPLE), 3121910778619);
+        assert_eq!(part2(&parse(EXAMPLE).unwrap()), 3121910778619);
     }
 
     #[test]
@@ -95,4 +120,10 @@ mod tests {
         assert_eq!(max_joltage_k(&[2, 3, 4, 2, 3, 4, 2, 3, 4, 2, 3, 4, 2, 7, 8], 12), 434234234278);
         assert_eq!(max_joltage_k(&[8, 1, 8, 1, 8, 1, 9, 1, 1, 1, 1, 2, 1, 1, 1], 12), 888911112111);
     }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(parse("98765432111x"), Err(ParseError::new(1, 12, "digit")));
+        assert_eq!(parse("987654321111\n9876"), Err(ParseError::new(2, 5, "a bank of at least 12 batteries")));
+    }
 }
@@ -1,93 +1,59 @@
-fn parse(input: &str) -> Vec<Vec<char>> {
-    input.lines().map(|line| line.chars().collect()).collect()
-}
+use crate::grid::{Grid, Point};
+use crate::parse::ParseError;
+use crate::solution::{Answer, Solution};
 
-fn part1(input: &str) -> usize {
-    let grid = parse(input);
-    let rows = grid.len();
-    let cols = if rows > 0 { grid[0].len() } else { 0 };
-
-    let mut count = 0;
-
-    for r in 0..rows {
-        for c in 0..cols {
-            if grid[r][c] != '@' {
-                continue;
-            }
-
-            // Count adjacent paper rolls
-            let mut adjacent = 0;
-            for dr in -1..=1i32 {
-                for dc in -1..=1i32 {
-                    if dr == 0 && dc == 0 {
-                        continue;
-                    }
-                    let nr = r as i32 + dr;
-                    let nc = c as i32 + dc;
-                    if nr >= 0 && nr < rows as i32 && nc >= 0 && nc < cols as i32 {
-                        if grid[nr as usize][nc as usize] == '@' {
-                            adjacent += 1;
-                        }
-                    }
-                }
-            }
-
-            // Accessible if fewer than 4 adjacent rolls
-            if adjacent < 4 {
-                count += 1;
-            }
-        }
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
     }
 
-    count
-}
+    fn part1(input: &Self::Input) -> Result<Answer, String> {
+        Ok(part1(input).into())
+    }
 
-fn count_adjacent(grid: &Vec<Vec<char>>, r: usize, c: usize) -> usize {
-    let rows = grid.len();
-    let cols = grid[0].len();
-    let mut adjacent = 0;
-    for dr in -1..=1i32 {
-        for dc in -1..=1i32 {
-            if dr == 0 && dc == 0 {
-                continue;
-            }
-            let nr = r as i32 + dr;
-            let nc = c as i32 + dc;
-            if nr >= 0 && nr < rows as i32 && nc >= 0 && nc < cols as i32 {
-                if grid[nr as usize][nc as usize] == '@' {
-                    adjacent += 1;
-                }
-            }
-        }
+    fn part2(input: &Self::Input) -> Result<Answer, String> {
+        Ok(part2(input).into())
     }
-    adjacent
 }
 
-fn part2(input: &str) -> usize {
-    let mut grid = parse(input);
-    let rows = grid.len();
-    let cols = if rows > 0 { grid[0].len() } else { 0 };
+pub fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(input, ".@")
+}
 
+pub fn part1(grid: &Grid<char>) -> usize {
+    // Accessible if fewer than 4 adjacent rolls
+    grid.find_all(&'@')
+        .filter(|&p| count_adjacent(grid, p) < 4)
+        .count()
+}
+
+// Count adjacent paper rolls
+pub fn count_adjacent(grid: &Grid<char>, p: Point) -> usize {
+    grid.neighbours8(p).filter(|&n| grid[n] == '@').count()
+}
+
+pub fn part2(grid: &Grid<char>) -> usize {
+    let mut grid = grid.clone();
     let mut total_removed = 0;
 
     loop {
         // Find all accessible rolls (fewer than 4 adjacent)
-        let mut to_remove = Vec::new();
-        for r in 0..rows {
-            for c in 0..cols {
-                if grid[r][c] == '@' && count_adjacent(&grid, r, c) < 4 {
-                    to_remove.push((r, c));
-                }
-            }
-        }
+        let to_remove: Vec<Point> = grid
+            .find_all(&'@')
+            .filter(|&p| count_adjacent(&grid, p) < 4)
+            .collect();
 
         if to_remove.is_empty() {
             break;
         }
 
         // Remove all accessible rolls
-        for (r, c) in &to_remove {
-            grid[*r][*c] = '.';
+        for &p in &to_remove {
+            grid[p] = '.';
         }
         total_removed += to_remove.len();
     }
@@ -95,12 +61,6 @@ fn part2(input: &str) -> usize {
     total_removed
 }
 
-fn main() {
-    let input = std::fs::read_to_string("data/04.txt").unwrap();
-    println!("part1: {}", part1(&input));
-    println!("part2: {}", part2(&input));
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -118,11 +78,11 @@ mod tests {
 
     #[test]
     fn test_part1() {
-        assert_eq!(part1(EXAMPLE), 13);
+        assert_eq!(part1(&parse(EXAMPLE).unwrap()), 13);
     }
 
     #[test]
     fn test_part2() {
-        assert_eq!(part2(EXAMPLE), 43);
+        assert_eq!(part2(&parse(EXAMPLE).unwrap()), 43);
     }
 }
@@ -1,66 +1,65 @@
-use std::fs;
+use crate::interval_set::IntervalSet;
+use crate::parse::{self, ParseError};
+use crate::solution::{Answer, Solution};
 
-fn parse(input: &str) -> (Vec<(u64, u64)>, Vec<u64>) {
-    let parts: Vec<&str> = input.split("\n\n").collect();
+pub struct Day05;
 
-    let ranges: Vec<(u64, u64)> = parts[0]
-        .lines()
-        .map(|line| {
-            let mut nums = line.split('-');
-            let start: u64 = nums.next().unwrap().parse().unwrap();
-            let end: u64 = nums.next().unwrap().parse().unwrap();
-            (start, end)
-        })
-        .collect();
+impl Solution for Day05 {
+    type Input = Database;
 
-    let ingredients: Vec<u64> = parts[1]
-        .lines()
-        .filter(|line| !line.is_empty())
-        .map(|line| line.parse().unwrap())
-        .collect();
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
 
-    (ranges, ingredients)
-}
+    fn part1(input: &Self::Input) -> Result<Answer, String> {
+        Ok(part1(&input.0, &input.1).into())
+    }
 
-fn is_fresh(id: u64, ranges: &[(u64, u64)]) -> bool {
-    ranges.iter().any(|&(start, end)| id >= start && id <= end)
+    fn part2(input: &Self::Input) -> Result<Answer, String> {
+        part2(&input.0).try_into()
+    }
 }
 
-fn part1(input: &str) -> usize {
-    let (ranges, ingredients) = parse(input);
-    ingredients.iter().filter(|&&id| is_fresh(id, &ranges)).count()
-}
+// Fresh ID ranges and the available ingredient IDs
+pub type Database = (IntervalSet<u64>, Vec<u64>);
+
+pub fn parse(input: &str) -> Result<Database, ParseError> {
+    let (ranges_part, ingredients_part) = input
+        .split_once("\n\n")
+        .ok_or_else(|| ParseError::at(input, &input[input.len()..], "blank line after the ranges"))?;
 
-fn merge_ranges(ranges: &[(u64, u64)]) -> Vec<(u64, u64)> {
-    let mut sorted: Vec<(u64, u64)> = ranges.to_vec();
-    sorted.sort_by_key(|r| r.0);
-
-    let mut merged: Vec<(u64, u64)> = Vec::new();
-    for (start, end) in sorted {
-        if let Some(last) = merged.last_mut() {
-            // Check if current range overlaps or is adjacent to the last merged range
-            if start <= last.1 + 1 {
-                last.1 = last.1.max(end);
-            } else {
-                merged.push((start, end));
+    let fresh: IntervalSet<u64> = ranges_part
+        .lines()
+        .map(|line| {
+            let (start, end) = line
+                .split_once('-')
+                .ok_or_else(|| ParseError::at(input, line, "range like 3-5"))?;
+            let start = parse::number(input, start, "range start")?;
+            let end = parse::number(input, end, "range end")?;
+            // IntervalSet would drop a reversed range without a word
+            if start > end {
+                return Err(ParseError::at(input, line, "range from low to high"));
             }
-        } else {
-            merged.push((start, end));
-        }
-    }
-    merged
+            Ok(start..=end)
+        })
+        .collect::<Result<_, _>>()?;
+
+    let ingredients: Vec<u64> = ingredients_part
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| parse::number(input, line, "ingredient ID"))
+        .collect::<Result<_, _>>()?;
+
+    Ok((fresh, ingredients))
 }
 
-fn part2(input: &str) -> u64 {
-    let (ranges, _) = parse(input);
-    let merged = merge_ranges(&ranges);
-    merged.iter().map(|(start, end)| end - start + 1).sum()
+pub fn part1(fresh: &IntervalSet<u64>, ingredients: &[u64]) -> usize {
+    ingredients.iter().filter(|&&id| fresh.contains(id)).count()
 }
 
-fn main() {
-    let input = fs::read_to_string("data/05.txt").unwrap();
-    println!("part1: {}", part1(&input));
-    println!("part2: {}", part2(&input));
+pub fn part2(fresh: &IntervalSet<u64>) -> u128 {
+    // Overlapping ranges are merged on insert, so each ID counts once
+    fresh.len()
 }
 
 #[cfg(test)]
@@ -81,11 +80,25 @@ mod tests {
 
     #[test]
     fn test_part1() {
-        assert_eq!(part1(EXAMPLE), 3);
+        let (ranges, ingredients) = parse(EXAMPLE).unwrap();
+        assert_eq!(part1(&ranges, &ingredients), 3);
     }
 
     #[test]
     fn test_part2() {
-        assert_eq!(part2(EXAMPLE), 14);
+        let (ranges, _) = parse(EXAMPLE).unwrap();
+        assert_eq!(part2(&ranges), 14);
+
+        // Every ID is fresh, one more than a u64 holds
+        let (ranges, _) = parse("0-18446744073709551615\n\n1").unwrap();
+        assert_eq!(part2(&ranges), 1 << 64);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(parse("3-5\n10-14"), Err(ParseError::new(2, 6, "blank line after the ranges")));
+        assert_eq!(parse("3-5\n10\n\n1"), Err(ParseError::new(2, 1, "range like 3-5")));
+        assert_eq!(parse("3-5\n14-10\n\n1"), Err(ParseError::new(2, 1, "range from low to high")));
+        assert_eq!(parse("3-5\n\n1\nx"), Err(ParseError::new(4, 1, "ingredient ID")));
     }
 }
@@ -1,13 +1,32 @@
-fn main() {
-    let input = std::fs::read_to_string("data/06.txt").unwrap();
-    println!("part1: {}", part1(&input));
-    println!("part2: {}", part2(&input));
+use crate::parse::ParseError;
+use crate::solution::{Answer, Solution};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = (Vec<(char, Vec<u64>)>, Vec<(char, Vec<u64>)>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok((parse(input)?, parse2(input)?))
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, String> {
+        part1(&input.0).map(Answer::from).ok_or_else(overflow)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, String> {
+        part2(&input.1).map(Answer::from).ok_or_else(overflow)
+    }
 }
 
-fn parse(input: &str) -> Vec<(char, Vec<u64>)> {
+fn overflow() -> String {
+    "the grand total overflows 64 bits".to_string()
+}
+
+pub fn parse(input: &str) -> Result<Vec<(char, Vec<u64>)>, ParseError> {
     let lines: Vec<&str> = input.lines().collect();
     if lines.is_empty() {
-        return vec![];
+        return Ok(vec![]);
     }
 
     // Find max width
@@ -17,7 +36,10 @@ fn parse(input: &str) -> Vec<(char, Vec<u64>)> {
     let padded: Vec<String> = lines.iter().map(|l| format!("{:width$}", l, width = max_width)).collect();
 
     // The last non-empty line contains operators
-    let operator_line = padded.iter().rev().find(|l| !l.trim().is_empty()).unwrap();
+    let op_line_idx = padded
+        .iter()
+        .rposition(|l| !l.trim().is_empty())
+        .ok_or_else(|| ParseError::new(1, 1, "operator line"))?;
 
     // Parse columns into problems
     let mut problems: Vec<(char, Vec<u64>)> = vec![];
@@ -26,7 +48,7 @@ fn parse(input: &str) -> Vec<(char, Vec<u64>)> {
     while col < max_width {
         // Skip separator columns (all spaces)
         let is_separator = padded.iter().all(|line| {
-            line.chars().nth(col).map_or(true, |c| c == ' ')
+            line.chars().nth(col).is_none_or(|c| c == ' ')
         });
 
         if is_separator {
@@ -38,7 +60,7 @@ fn parse(input: &str) -> Vec<(char, Vec<u64>)> {
         let start_col = col;
         while col < max_width {
             let is_sep = padded.iter().all(|line| {
-                line.chars().nth(col).map_or(true, |c| c == ' ')
+                line.chars().nth(col).is_none_or(|c| c == ' ')
             });
             if is_sep {
                 break;
@@ -48,20 +70,21 @@ fn parse(input: &str) -> Vec<(char, Vec<u64>)> {
         let end_col = col;
 
         // Extract the problem: get the operator and numbers
-        let problem_str: String = operator_line.chars().skip(start_col).take(end_col - start_col).collect();
-        let op = problem_str.trim().chars().next().unwrap_or('+');
+        let problem_str: String = padded[op_line_idx].chars().skip(start_col).take(end_col - start_col).collect();
+        let op = operator(&problem_str, op_line_idx, start_col)?;
 
         let mut numbers = vec![];
-        for line in &padded {
-            if line == operator_line {
+        for (line_idx, line) in padded.iter().enumerate() {
+            if line_idx == op_line_idx {
                 continue;
             }
             let num_str: String = line.chars().skip(start_col).take(end_col - start_col).collect();
             let num_str = num_str.trim();
             if !num_str.is_empty() {
-                if let Ok(n) = num_str.parse::<u64>() {
-                    numbers.push(n);
-                }
+                let n = num_str
+                    .parse()
+                    .map_err(|_| ParseError::new(line_idx + 1, start_col + 1, "number"))?;
+                numbers.push(n);
             }
         }
 
@@ -70,27 +93,37 @@ fn parse(input: &str) -> Vec<(char, Vec<u64>)> {
         }
     }
 
-    problems
+    Ok(problems)
 }
 
-fn part1(input: &str) -> u64 {
-    let problems = parse(input);
-    problems
-        .iter()
-        .map(|(op, nums)| {
-            match op {
-                '+' => nums.iter().sum(),
-                '*' => nums.iter().product(),
-                _ => 0,
-            }
-        })
-        .sum()
+// The operator under a problem, anywhere within its columns
+fn operator(problem_str: &str, op_line_idx: usize, start_col: usize) -> Result<char, ParseError> {
+    match problem_str.trim().chars().next() {
+        Some(op @ ('+' | '*')) => Ok(op),
+        _ => Err(ParseError::new(op_line_idx + 1, start_col + 1, "operator + or *")),
+    }
+}
+
+// The sum of every problem's result, or None if it doesn't fit in 64 bits
+fn grand_total(problems: &[(char, Vec<u64>)]) -> Option<u64> {
+    problems.iter().try_fold(0u64, |total, (op, nums)| {
+        let result = match op {
+            '+' => nums.iter().try_fold(0u64, |acc, &n| acc.checked_add(n)),
+            '*' => nums.iter().try_fold(1u64, |acc, &n| acc.checked_mul(n)),
+            _ => Some(0),
+        };
+        total.checked_add(result?)
+    })
 }
 
-fn parse2(input: &str) -> Vec<(char, Vec<u64>)> {
+pub fn part1(problems: &[(char, Vec<u64>)]) -> Option<u64> {
+    grand_total(problems)
+}
+
+pub fn parse2(input: &str) -> Result<Vec<(char, Vec<u64>)>, ParseError> {
     let lines: Vec<&str> = input.lines().collect();
     if lines.is_empty() {
-        return vec![];
+        return Ok(vec![]);
     }
 
     // Find max width
@@ -106,7 +139,7 @@ fn parse2(input: &str) -> Vec<(char, Vec<u64>)> {
     let op_line_idx = padded
         .iter()
         .rposition(|l| !l.trim().is_empty())
-        .unwrap();
+        .ok_or_else(|| ParseError::new(1, 1, "operator line"))?;
 
     // Read columns right-to-left, grouping by problems (separated by all-space columns)
     let mut problems: Vec<(char, Vec<u64>)> = vec![];
@@ -117,7 +150,7 @@ fn parse2(input: &str) -> Vec<(char, Vec<u64>)> {
 
         // Skip separator columns (all spaces)
         let is_separator = padded.iter().all(|line| {
-            line.chars().nth(c).map_or(true, |ch| ch == ' ')
+            line.chars().nth(c).is_none_or(|ch| ch == ' ')
         });
 
         if is_separator {
@@ -130,7 +163,7 @@ fn parse2(input: &str) -> Vec<(char, Vec<u64>)> {
         while col >= 0 {
             let cc = col as usize;
             let is_sep = padded.iter().all(|line| {
-                line.chars().nth(cc).map_or(true, |ch| ch == ' ')
+                line.chars().nth(cc).is_none_or(|ch| ch == ' ')
             });
             if is_sep {
                 break;
@@ -145,27 +178,35 @@ fn parse2(input: &str) -> Vec<(char, Vec<u64>)> {
             .skip(start_col)
             .take(end_col - start_col + 1)
             .collect();
-        let op = op_str.trim().chars().next().unwrap_or('+');
+        let op = operator(&op_str, op_line_idx, start_col)?;
 
         // Each column within the problem is a number (digits top to bottom, MSD first)
         // Read columns right-to-left within the problem
         let mut numbers = vec![];
         for problem_col in (start_col..=end_col).rev() {
             let mut num_str = String::new();
+            // The line the number's top digit is on
+            let mut first_line = 0;
             for (line_idx, line) in padded.iter().enumerate() {
                 if line_idx == op_line_idx {
                     continue;
                 }
-                if let Some(ch) = line.chars().nth(problem_col) {
-                    if ch.is_ascii_digit() {
+                match line.chars().nth(problem_col) {
+                    Some(ch) if ch.is_ascii_digit() => {
+                        if num_str.is_empty() {
+                            first_line = line_idx;
+                        }
                         num_str.push(ch);
                     }
+                    Some(' ') | None => {}
+                    Some(_) => return Err(ParseError::new(line_idx + 1, problem_col + 1, "digit")),
                 }
             }
             if !num_str.is_empty() {
-                if let Ok(n) = num_str.parse::<u64>() {
-                    numbers.push(n);
-                }
+                let n = num_str
+                    .parse()
+                    .map_err(|_| ParseError::new(first_line + 1, problem_col + 1, "number"))?;
+                numbers.push(n);
             }
         }
 
@@ -174,19 +215,11 @@ fn parse2(input: &str) -> Vec<(char, Vec<u64>)> {
         }
     }
 
-    problems
+    Ok(problems)
 }
 
-fn part2(input: &str) -> u64 {
-    let problems = parse2(input);
-    problems
-        .iter()
-        .map(|(op, nums)| match op {
-            '+' => nums.iter().sum(),
-            '*' => nums.iter().product(),
-            _ => 0,
-        })
-        .sum()
+pub fn part2(problems: &[(char, Vec<u64>)]) -> Option<u64> {
+    grand_total(problems)
 }
 
 #[cfg(test)]
@@ -199,7 +232,7 @@ mod tests {
  45 64  387 23
   6 98  215 314
 *   +   *   +";
-        assert_eq!(part1(input), 4277556);
+        assert_eq!(part1(&parse(input).unwrap()), Some(4277556));
     }
 
     #[test]
@@ -208,7 +241,7 @@ mod tests {
  45 64  387 23
   6 98  215 314
 *   +   *   +";
-        let problems = parse(input);
+        let problems = parse(input).unwrap();
         assert_eq!(problems.len(), 4);
 
         // 123 * 45 * 6 = 33210
@@ -230,7 +263,7 @@ mod tests {
  45 64  387 23
   6 98  215 314
 *   +   *   +";
-        assert_eq!(part2(input), 3263827);
+        assert_eq!(part2(&parse2(input).unwrap()), Some(3263827));
     }
 
     #[test]
@@ -239,7 +272,7 @@ mod tests {
  45 64  387 23
   6 98  215 314
 *   +   *   +";
-        let problems = parse2(input);
+        let problems = parse2(input).unwrap();
 
         // Reading right to left:
         // Rightmost: 4 + 431 + 623 = 1058
@@ -252,4 +285,23 @@ mod tests {
         assert_eq!(problems[2], ('+', vec![8, 248, 369]));
         assert_eq!(problems[3], ('*', vec![356, 24, 1]));
     }
+
+    #[test]
+    fn test_overflow() {
+        let input = "9999999999 9999999999\n9999999999 9999999999\n*          *";
+        assert_eq!(part1(&parse(input).unwrap()), None);
+        assert_eq!(Day06::part1(&Day06::parse(input).unwrap()), Err(overflow()));
+        assert_eq!(part2(&parse2("99\n99\n+ ").unwrap()), Some(198));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(parse("1\n2\n-"), Err(ParseError::new(3, 1, "operator + or *")));
+
+        // The right-hand column reads top to bottom as a number too big for
+        // 64 bits, starting on line 3
+        let tall = format!("1\n1\n{}+", "19\n".repeat(20));
+        assert_eq!(parse2(&tall), Err(ParseError::new(3, 2, "number")));
+        assert_eq!(parse2("12\n3x\n+"), Err(ParseError::new(2, 2, "digit")));
+    }
 }
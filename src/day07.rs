@@ -1,35 +1,56 @@
 use std::collections::{HashMap, HashSet};
 
-fn parse(input: &str) -> (Vec<Vec<char>>, (usize, usize)) {
-    let grid: Vec<Vec<char>> = input.lines().map(|l| l.chars().collect()).collect();
-    let mut start = (0, 0);
-    for (row, line) in grid.iter().enumerate() {
-        for (col, &ch) in line.iter().enumerate() {
-            if ch == 'S' {
-                start = (row, col);
-            }
-        }
+use crate::grid::{Grid, Point};
+use crate::parse::ParseError;
+use crate::solution::{Answer, Solution};
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Manifold;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, String> {
+        Ok(part1(&input.0, input.1).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, String> {
+        part2(&input.0, input.1)
+            .ok_or("the timelines overflow 128 bits")?
+            .try_into()
     }
-    (grid, start)
 }
 
-fn part1(input: &str) -> usize {
-    let (grid, start) = parse(input);
-    let rows = grid.len();
-    let cols = grid[0].len();
+// The diagram and the position of S
+pub type Manifold = (Grid<char>, Point);
+
+pub fn parse(input: &str) -> Result<Manifold, ParseError> {
+    let grid = Grid::parse(input, ".^S")?;
+    let start = grid
+        .find(&'S')
+        .ok_or_else(|| ParseError::new(1, 1, "start position S"))?;
+    Ok((grid, start))
+}
+
+pub fn part1(grid: &Grid<char>, start: Point) -> usize {
+    let rows = grid.height();
+    let cols = grid.width();
 
     // Track positions where beams are active (beams merge at same position)
     let mut beam_positions: HashSet<usize> = HashSet::new();
-    beam_positions.insert(start.1);
+    beam_positions.insert(start.col);
 
     let mut splits = 0;
 
     // Process row by row, starting from S
-    for row in start.0..rows {
+    for row in start.row..rows {
         let mut next_positions: HashSet<usize> = HashSet::new();
 
         for &col in &beam_positions {
-            if grid[row][col] == '^' {
+            if grid[Point::new(row, col)] == '^' {
                 // Beam hits splitter - count this split
                 splits += 1;
 
@@ -52,31 +73,37 @@ fn part1(input: &str) -> usize {
     splits
 }
 
-fn part2(input: &str) -> u64 {
-    let (grid, start) = parse(input);
-    let rows = grid.len();
-    let cols = grid[0].len();
+// Timelines double at every splitter a beam hits, so tall diagrams outgrow any
+// integer: None if the count doesn't fit in 128 bits
+pub fn part2(grid: &Grid<char>, start: Point) -> Option<u128> {
+    let rows = grid.height();
+    let cols = grid.width();
 
     // Track number of timelines at each position
-    let mut timelines: HashMap<usize, u64> = HashMap::new();
-    timelines.insert(start.1, 1);
+    let mut timelines: HashMap<usize, u128> = HashMap::new();
+    timelines.insert(start.col, 1);
 
     // Process row by row, starting from S
-    for row in start.0..rows {
-        let mut next_timelines: HashMap<usize, u64> = HashMap::new();
+    for row in start.row..rows {
+        let mut next_timelines: HashMap<usize, u128> = HashMap::new();
+        let mut add = |col: usize, count: u128| {
+            let entry = next_timelines.entry(col).or_insert(0);
+            *entry = entry.checked_add(count)?;
+            Some(())
+        };
 
         for (&col, &count) in &timelines {
-            if grid[row][col] == '^' {
+            if grid[Point::new(row, col)] == '^' {
                 // Each timeline splits into two (left and right)
                 if col > 0 {
-                    *next_timelines.entry(col - 1).or_insert(0) += count;
+                    add(col - 1, count)?;
                 }
                 if col + 1 < cols {
-                    *next_timelines.entry(col + 1).or_insert(0) += count;
+                    add(col + 1, count)?;
                 }
             } else {
                 // Timeline continues through this position
-                *next_timelines.entry(col).or_insert(0) += count;
+                add(col, count)?;
             }
         }
 
@@ -84,13 +111,7 @@ fn part2(input: &str) -> u64 {
     }
 
     // Sum all timelines at the end
-    timelines.values().sum()
-}
-
-fn main() {
-    let input = std::fs::read_to_string("data/07.txt").unwrap();
-    println!("part1: {}", part1(&input));
-    println!("part2: {}", part2(&input));
+    timelines.values().try_fold(0u128, |total, &count| total.checked_add(count))
 }
 
 #[cfg(test)]
@@ -116,11 +137,39 @@ mod tests {
 
     #[test]
     fn test_part1() {
-        assert_eq!(part1(EXAMPLE), 21);
+        let (grid, start) = parse(EXAMPLE).unwrap();
+        assert_eq!(part1(&grid, start), 21);
     }
 
     #[test]
     fn test_part2() {
-        assert_eq!(part2(EXAMPLE), 40);
+        let (grid, start) = parse(EXAMPLE).unwrap();
+        assert_eq!(part2(&grid, start), Some(40));
+    }
+
+    // S over a full triangle of splitters, `levels` deep, so every timeline
+    // splits on every level
+    fn triangle(levels: usize) -> String {
+        let width = 2 * levels + 3;
+        let mut rows = vec![format!("{}S{}", ".".repeat(levels + 1), ".".repeat(levels + 1)), ".".repeat(width)];
+        for level in 0..levels {
+            let mut row = vec!['.'; width];
+            for i in 0..=level {
+                row[levels + 1 - level + 2 * i] = '^';
+            }
+            rows.push(row.into_iter().collect());
+            rows.push(".".repeat(width));
+        }
+        rows.join("\n")
+    }
+
+    #[test]
+    fn test_part2_past_64_bits() {
+        let (grid, start) = parse(&triangle(80)).unwrap();
+        assert_eq!(grid.height(), 162);
+        assert_eq!(part2(&grid, start), Some(1 << 80));
+        let (grid, start) = parse(&triangle(130)).unwrap();
+        assert_eq!(part2(&grid, start), None);
+        assert_eq!(Day07::part2(&(grid, start)), Err("the timelines overflow 128 bits".to_string()));
     }
 }
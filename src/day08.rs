@@ -1,134 +1,99 @@
-fn main() {
-    let input = std::fs::read_to_string("data/08.txt").unwrap();
-    println!("part1: {}", part1(&input));
-    println!("part2: {}", part2(&input));
-}
+use crate::geometry::Point3;
+use crate::parse::{self, ParseError};
+use crate::solution::{Answer, Solution};
+use crate::union_find::UnionFind;
 
-fn parse(input: &str) -> Vec<(i64, i64, i64)> {
-    input
-        .lines()
-        .filter(|line| !line.is_empty())
-        .map(|line| {
-            let coords: Vec<i64> = line.split(',').map(|s| s.parse().unwrap()).collect();
-            (coords[0], coords[1], coords[2])
-        })
-        .collect()
-}
+pub struct Day08;
 
-fn distance_squared(a: &(i64, i64, i64), b: &(i64, i64, i64)) -> i64 {
-    let dx = a.0 - b.0;
-    let dy = a.1 - b.1;
-    let dz = a.2 - b.2;
-    dx * dx + dy * dy + dz * dz
-}
+impl Solution for Day08 {
+    type Input = Vec<Point3>;
 
-struct UnionFind {
-    parent: Vec<usize>,
-    rank: Vec<usize>,
-    size: Vec<usize>,
-}
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
 
-impl UnionFind {
-    fn new(n: usize) -> Self {
-        UnionFind {
-            parent: (0..n).collect(),
-            rank: vec![0; n],
-            size: vec![1; n],
-        }
+    fn part1(input: &Self::Input) -> Result<Answer, String> {
+        Ok(part1(input).into())
     }
 
-    fn find(&mut self, x: usize) -> usize {
-        if self.parent[x] != x {
-            self.parent[x] = self.find(self.parent[x]);
-        }
-        self.parent[x]
+    fn part2(input: &Self::Input) -> Result<Answer, String> {
+        Ok(part2(input).into())
     }
+}
 
-    fn union(&mut self, x: usize, y: usize) {
-        let px = self.find(x);
-        let py = self.find(y);
-        if px == py {
-            return;
-        }
-        if self.rank[px] < self.rank[py] {
-            self.parent[px] = py;
-            self.size[py] += self.size[px];
-        } else if self.rank[px] > self.rank[py] {
-            self.parent[py] = px;
-            self.size[px] += self.size[py];
-        } else {
-            self.parent[py] = px;
-            self.size[px] += self.size[py];
-            self.rank[px] += 1;
-        }
+pub fn parse(input: &str) -> Result<Vec<Point3>, ParseError> {
+    let boxes: Vec<Point3> = input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let coords: Vec<&str> = line.split(',').collect();
+            if coords.len() != 3 {
+                return Err(ParseError::at(input, line, "X,Y,Z coordinates"));
+            }
+            Ok(Point3::new(
+                coordinate(input, coords[0], "X coordinate")?,
+                coordinate(input, coords[1], "Y coordinate")?,
+                coordinate(input, coords[2], "Z coordinate")?,
+            ))
+        })
+        .collect::<Result<_, _>>()?;
+    // Part 2 needs a last connection to make
+    if boxes.len() < 2 {
+        return Err(ParseError::at(input, &input[input.len()..], "at least two junction boxes"));
     }
+    Ok(boxes)
 }
 
-fn part1(input: &str) -> i64 {
-    let boxes = parse(input);
-    let n = boxes.len();
+// Squared distances must fit in an i64, so far-off boxes are refused
+fn coordinate(input: &str, s: &str, expected: &str) -> Result<i64, ParseError> {
+    let c: i64 = parse::number(input, s, expected)?;
+    if !(-MAX_COORDINATE..=MAX_COORDINATE).contains(&c) {
+        return Err(ParseError::at(input, s, format!("coordinate of at most {} either way", MAX_COORDINATE)));
+    }
+    Ok(c)
+}
+
+const MAX_COORDINATE: i64 = 100_000_000;
 
-    // Compute all pairwise distances
+// Every pair of boxes as (distance squared, i, j), closest first
+pub fn pairs_by_distance(boxes: &[Point3]) -> Vec<(i64, usize, usize)> {
+    let n = boxes.len();
     let mut distances: Vec<(i64, usize, usize)> = Vec::new();
     for i in 0..n {
         for j in (i + 1)..n {
-            let dist = distance_squared(&boxes[i], &boxes[j]);
+            let dist = boxes[i].distance_squared(boxes[j]);
             distances.push((dist, i, j));
         }
     }
-
-    // Sort by distance
     distances.sort_by_key(|&(d, _, _)| d);
+    distances
+}
 
-    // Make 1000 connections using Union-Find
-    let mut uf = UnionFind::new(n);
-    for &(_, i, j) in distances.iter().take(1000) {
+// Product of the three largest circuit sizes after connecting the
+// `connections` closest pairs
+pub fn largest_circuits_product(boxes: &[Point3], connections: usize) -> i64 {
+    let mut uf = UnionFind::new(boxes.len());
+    for &(_, i, j) in pairs_by_distance(boxes).iter().take(connections) {
         uf.union(i, j);
     }
 
-    // Find circuit sizes
-    let mut circuit_sizes: Vec<i64> = Vec::new();
-    for i in 0..n {
-        if uf.find(i) == i {
-            circuit_sizes.push(uf.size[i] as i64);
-        }
-    }
-
-    // Sort descending and multiply top 3
+    // Sort descending and multiply top 3 (or all, if fewer are left)
+    let mut circuit_sizes: Vec<i64> = uf.components().map(|c| c.len() as i64).collect();
     circuit_sizes.sort_by(|a, b| b.cmp(a));
-    circuit_sizes[0] * circuit_sizes[1] * circuit_sizes[2]
+    circuit_sizes.iter().take(3).product()
 }
 
-fn part2(input: &str) -> i64 {
-    let boxes = parse(input);
-    let n = boxes.len();
-
-    // Compute all pairwise distances
-    let mut distances: Vec<(i64, usize, usize)> = Vec::new();
-    for i in 0..n {
-        for j in (i + 1)..n {
-            let dist = distance_squared(&boxes[i], &boxes[j]);
-            distances.push((dist, i, j));
-        }
-    }
-
-    // Sort by distance
-    distances.sort_by_key(|&(d, _, _)| d);
+pub fn part1(boxes: &[Point3]) -> i64 {
+    largest_circuits_product(boxes, 1000)
+}
 
+pub fn part2(boxes: &[Point3]) -> i64 {
     // Keep connecting until all in one circuit
-    let mut uf = UnionFind::new(n);
-    let mut num_circuits = n;
-
-    for &(_, i, j) in &distances {
-        let pi = uf.find(i);
-        let pj = uf.find(j);
-        if pi != pj {
-            uf.union(i, j);
-            num_circuits -= 1;
-            if num_circuits == 1 {
-                // This was the last connection needed
-                return boxes[i].0 * boxes[j].0;
-            }
+    let mut uf = UnionFind::new(boxes.len());
+    for (_, i, j) in pairs_by_distance(boxes) {
+        if uf.union(i, j) && uf.component_count() == 1 {
+            // This was the last connection needed
+            return boxes[i].x * boxes[j].x;
         }
     }
 
@@ -163,38 +128,22 @@ mod tests {
     #[test]
     fn test_part1_example() {
         // After 10 connections: 5, 4, 2 => 5 * 4 * 2 = 40
-        let boxes = parse(EXAMPLE);
-        let n = boxes.len();
-
-        let mut distances: Vec<(i64, usize, usize)> = Vec::new();
-        for i in 0..n {
-            for j in (i + 1)..n {
-                let dist = distance_squared(&boxes[i], &boxes[j]);
-                distances.push((dist, i, j));
-            }
-        }
-        distances.sort_by_key(|&(d, _, _)| d);
-
-        let mut uf = UnionFind::new(n);
-        for &(_, i, j) in distances.iter().take(10) {
-            uf.union(i, j);
-        }
-
-        let mut circuit_sizes: Vec<i64> = Vec::new();
-        for i in 0..n {
-            if uf.find(i) == i {
-                circuit_sizes.push(uf.size[i] as i64);
-            }
-        }
-        circuit_sizes.sort_by(|a, b| b.cmp(a));
-
-        assert_eq!(circuit_sizes[0] * circuit_sizes[1] * circuit_sizes[2], 40);
+        assert_eq!(largest_circuits_product(&parse(EXAMPLE).unwrap(), 10), 40);
     }
 
     #[test]
     fn test_part2_example() {
         // Last connection is between 216,146,977 and 117,168,530
         // 216 * 117 = 25272
-        assert_eq!(part2(EXAMPLE), 25272);
+        assert_eq!(part2(&parse(EXAMPLE).unwrap()), 25272);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(parse("1,2,3\n4,5"), Err(ParseError::new(2, 1, "X,Y,Z coordinates")));
+        assert_eq!(parse("1,2,3\n4,5,z"), Err(ParseError::new(2, 5, "Z coordinate")));
+        assert_eq!(parse("1,2,3\n"), Err(ParseError::new(2, 1, "at least two junction boxes")));
+        let far = "coordinate of at most 100000000 either way";
+        assert_eq!(parse("0,0,0\n-9223372036854775808,0,0"), Err(ParseError::new(2, 1, far)));
     }
 }
@@ -1,38 +1,59 @@
-fn main() {
-    let input = std::fs::read_to_string("data/09.txt").unwrap();
-    println!("part1: {}", part1(&input));
-    println!("part2: {}", part2(&input));
+use crate::geometry::{Point2, Rect, Segment};
+use crate::parse::{self, ParseError};
+use crate::solution::{Answer, Solution};
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<Point2>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, String> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, String> {
+        Ok(part2(input).into())
+    }
 }
 
-fn parse(input: &str) -> Vec<(i64, i64)> {
+pub fn parse(input: &str) -> Result<Vec<Point2>, ParseError> {
     input
         .lines()
         .filter(|line| !line.is_empty())
         .map(|line| {
-            let mut parts = line.split(',');
-            let x = parts.next().unwrap().parse().unwrap();
-            let y = parts.next().unwrap().parse().unwrap();
-            (x, y)
+            let (x, y) = line
+                .split_once(',')
+                .ok_or_else(|| ParseError::at(input, line, "X,Y coordinates"))?;
+            let x = coordinate(input, x, "X coordinate")?;
+            let y = coordinate(input, y, "Y coordinate")?;
+            Ok(Point2::new(x, y))
         })
         .collect()
 }
 
-fn part1(input: &str) -> i64 {
-    let tiles = parse(input);
+// Rectangle areas must fit in an i64, so far-off tiles are refused
+fn coordinate(input: &str, s: &str, expected: &str) -> Result<i64, ParseError> {
+    let c: i64 = parse::number(input, s, expected)?;
+    if !(-MAX_COORDINATE..=MAX_COORDINATE).contains(&c) {
+        return Err(ParseError::at(input, s, format!("coordinate of at most {} either way", MAX_COORDINATE)));
+    }
+    Ok(c)
+}
+
+const MAX_COORDINATE: i64 = 100_000_000;
+
+pub fn part1(tiles: &[Point2]) -> i64 {
     let mut max_area = 0;
 
     // Try all pairs of tiles as opposite corners
     for i in 0..tiles.len() {
         for j in (i + 1)..tiles.len() {
-            let (x1, y1) = tiles[i];
-            let (x2, y2) = tiles[j];
-
-            // Calculate rectangle dimensions (inclusive of both corners)
-            let width = (x2 - x1).abs() + 1;
-            let height = (y2 - y1).abs() + 1;
-
-            // Area of rectangle with these as opposite corners
-            let area = width * height;
+            // Area counts tiles, inclusive of both corners
+            let area = Rect::from_corners(tiles[i], tiles[j]).area();
             max_area = max_area.max(area);
         }
     }
@@ -40,24 +61,19 @@ fn part1(input: &str) -> i64 {
     max_area
 }
 
-fn part2(input: &str) -> i64 {
-    let tiles = parse(input);
+pub fn part2(tiles: &[Point2]) -> i64 {
     let n = tiles.len();
+    let floor = Floor::new(tiles);
 
     let mut max_area = 0;
 
     for i in 0..n {
         for j in (i + 1)..n {
-            let (x1, y1) = tiles[i];
-            let (x2, y2) = tiles[j];
-
-            let width = (x2 - x1).abs() + 1;
-            let height = (y2 - y1).abs() + 1;
-            let area = width * height;
+            let rect = Rect::from_corners(tiles[i], tiles[j]);
 
             // Check if rectangle is valid (all inside/on polygon)
-            if is_rect_valid(&tiles, x1, y1, x2, y2) {
-                max_area = max_area.max(area);
+            if floor.contains(&rect) {
+                max_area = max_area.max(rect.area());
             }
         }
     }
@@ -65,83 +81,74 @@ fn part2(input: &str) -> i64 {
     max_area
 }
 
-// Check if a rectangle with opposite corners (x1,y1) and (x2,y2) is entirely within the polygon
-fn is_rect_valid(polygon: &[(i64, i64)], x1: i64, y1: i64, x2: i64, y2: i64) -> bool {
-    let min_x = x1.min(x2);
-    let max_x = x1.max(x2);
-    let min_y = y1.min(y2);
-    let max_y = y1.max(y2);
-
-    // Check all 4 corners
-    let corners = [
-        (min_x, min_y),
-        (min_x, max_y),
-        (max_x, min_y),
-        (max_x, max_y),
-    ];
-
-    for &corner in &corners {
-        if !point_in_polygon(corner, polygon) {
-            return false;
-        }
-    }
-
-    // Check all 4 edges don't cross the polygon boundary improperly
-    // For a rectilinear polygon and axis-aligned rectangle, if all 4 corners are inside,
-    // and the edges don't cross polygon edges, then the rectangle is valid.
+fn polygon_edges(polygon: &[Point2]) -> impl Iterator<Item = Segment> + '_ {
+    let n = polygon.len();
+    (0..n).map(move |i| Segment::new(polygon[i], polygon[(i + 1) % n]))
+}
 
-    // Check each edge of the rectangle against each edge of the polygon
-    let rect_edges = [
-        ((min_x, min_y), (max_x, min_y)), // bottom
-        ((min_x, max_y), (max_x, max_y)), // top
-        ((min_x, min_y), (min_x, max_y)), // left
-        ((max_x, min_y), (max_x, max_y)), // right
-    ];
+// The tiles cut into blocks at every polygon coordinate and just past it.
+// Every tile of a block is in the polygon or every tile is out, sides one
+// tile apart included, so counting the blocks that are out answers for any
+// rectangle at once.
+pub struct Floor {
+    xs: Vec<i64>,
+    ys: Vec<i64>,
+    // outside[i][j] counts the blocks out of the polygon below column i and row j
+    outside: Vec<Vec<u32>>,
+}
 
-    let n = polygon.len();
-    for rect_edge in &rect_edges {
-        for i in 0..n {
-            let poly_edge = (polygon[i], polygon[(i + 1) % n]);
-            if edges_cross_interior(rect_edge, &poly_edge) {
-                return false;
+impl Floor {
+    pub fn new(polygon: &[Point2]) -> Floor {
+        let cuts = |coords: &mut dyn Iterator<Item = i64>| {
+            let mut cuts: Vec<i64> = coords.flat_map(|c| [c, c + 1]).collect();
+            cuts.sort();
+            cuts.dedup();
+            cuts
+        };
+        let xs = cuts(&mut polygon.iter().map(|p| p.x));
+        let ys = cuts(&mut polygon.iter().map(|p| p.y));
+
+        let mut outside = vec![vec![0; ys.len() + 1]; xs.len() + 1];
+        for (i, &x) in xs.iter().enumerate() {
+            for (j, &y) in ys.iter().enumerate() {
+                let out = !point_in_polygon(Point2::new(x, y), polygon) as u32;
+                outside[i + 1][j + 1] = outside[i][j + 1] + outside[i + 1][j] - outside[i][j] + out;
             }
         }
+        Floor { xs, ys, outside }
     }
 
-    true
+    pub fn contains(&self, rect: &Rect) -> bool {
+        // Before the first cut is outside the polygon
+        let (Some(&x), Some(&y)) = (self.xs.first(), self.ys.first()) else {
+            return false;
+        };
+        if rect.min.x < x || rect.min.y < y {
+            return false;
+        }
+        let block = |cuts: &[i64], c: i64| cuts.partition_point(|&cut| cut <= c);
+        let (i0, i1) = (block(&self.xs, rect.min.x) - 1, block(&self.xs, rect.max.x));
+        let (j0, j1) = (block(&self.ys, rect.min.y) - 1, block(&self.ys, rect.max.y));
+        let o = &self.outside;
+        o[i1][j1] + o[i0][j0] - o[i0][j1] - o[i1][j0] == 0
+    }
 }
 
 // Check if point is inside or on the boundary of the polygon
-fn point_in_polygon(point: (i64, i64), polygon: &[(i64, i64)]) -> bool {
-    let (px, py) = point;
-    let n = polygon.len();
-
+pub fn point_in_polygon(point: Point2, polygon: &[Point2]) -> bool {
     // First check if point is on any edge
-    for i in 0..n {
-        let (x1, y1) = polygon[i];
-        let (x2, y2) = polygon[(i + 1) % n];
-
-        if x1 == x2 {
-            // Vertical edge
-            let (min_y, max_y) = if y1 < y2 { (y1, y2) } else { (y2, y1) };
-            if px == x1 && py >= min_y && py <= max_y {
-                return true;
-            }
-        } else {
-            // Horizontal edge
-            let (min_x, max_x) = if x1 < x2 { (x1, x2) } else { (x2, x1) };
-            if py == y1 && px >= min_x && px <= max_x {
-                return true;
-            }
-        }
+    if polygon_edges(polygon).any(|edge| edge.contains(point)) {
+        return true;
     }
 
     // Ray casting algorithm for interior check
+    let (px, py) = (point.x, point.y);
+    let n = polygon.len();
     let mut inside = false;
     let mut j = n - 1;
     for i in 0..n {
-        let (xi, yi) = polygon[i];
-        let (xj, yj) = polygon[j];
+        let (xi, yi) = (polygon[i].x, polygon[i].y);
+        let (xj, yj) = (polygon[j].x, polygon[j].y);
 
         // Check if ray from point going right crosses this edge
         if ((yi > py) != (yj > py)) && (px < (xj - xi) * (py - yi) / (yj - yi) + xi) {
@@ -153,39 +160,14 @@ fn point_in_polygon(point: (i64, i64), polygon: &[(i64, i64)]) -> bool {
     inside
 }
 
-// Check if two axis-aligned segments cross in their interiors (perpendicular crossing only)
-// Parallel/overlapping segments are NOT considered crossings
-fn edges_cross_interior(e1: &((i64, i64), (i64, i64)), e2: &((i64, i64), (i64, i64))) -> bool {
-    let ((x1a, y1a), (x1b, y1b)) = *e1;
-    let ((x2a, y2a), (x2b, y2b)) = *e2;
-
-    let e1_horiz = y1a == y1b;
-    let e2_horiz = y2a == y2b;
-
-    // Parallel segments don't "cross" - they either overlap (allowed) or don't touch
-    if e1_horiz == e2_horiz {
-        return false;
-    }
-
-    if e1_horiz {
-        // e1 horizontal, e2 vertical
-        // They cross in interior if e2's x is strictly between e1's x range
-        // and e1's y is strictly between e2's y range
-        let (min_x1, max_x1) = (x1a.min(x1b), x1a.max(x1b));
-        let (min_y2, max_y2) = (y2a.min(y2b), y2a.max(y2b));
-        x2a > min_x1 && x2a < max_x1 && y1a > min_y2 && y1a < max_y2
-    } else {
-        // e1 vertical, e2 horizontal
-        let (min_y1, max_y1) = (y1a.min(y1b), y1a.max(y1b));
-        let (min_x2, max_x2) = (x2a.min(x2b), x2a.max(x2b));
-        x1a > min_x2 && x1a < max_x2 && y2a > min_y1 && y2a < max_y1
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn rect(x1: i64, y1: i64, x2: i64, y2: i64) -> Rect {
+        Rect::from_corners(Point2::new(x1, y1), Point2::new(x2, y2))
+    }
+
     const EXAMPLE: &str = "7,1
 11,1
 11,7
@@ -198,89 +180,88 @@ mod tests {
 
     #[test]
     fn test_part1() {
-        assert_eq!(part1(EXAMPLE), 50);
+        assert_eq!(part1(&parse(EXAMPLE).unwrap()), 50);
     }
 
     #[test]
     fn test_part2() {
-        assert_eq!(part2(EXAMPLE), 24);
+        assert_eq!(part2(&parse(EXAMPLE).unwrap()), 24);
     }
 
     #[test]
-    fn test_rect_valid() {
-        let polygon: Vec<(i64, i64)> = vec![
-            (7, 1),
-            (11, 1),
-            (11, 7),
-            (9, 7),
-            (9, 5),
-            (2, 5),
-            (2, 3),
-            (7, 3),
-        ];
+    fn test_part2_edge_turning_inward() {
+        // Two legs hang from a bar. The rectangle 2,8 to 8,0 has every corner
+        // on the polygon and no edge crossing its edges, but the gap between
+        // the legs cuts into it from below.
+        let polygon = "0,10\n2,10\n2,8\n4,8\n4,10\n6,10\n6,8\n8,8\n8,0\n6,0\n6,6\n4,6\n4,0\n2,0\n2,6\n0,6\n";
+        let tiles = parse(polygon).unwrap();
+        assert!(!Floor::new(&tiles).contains(&rect(2, 8, 8, 0)));
+        assert_eq!(part2(&tiles), 27);
+    }
+
+    #[test]
+    fn test_part2_sides_on_adjacent_tiles() {
+        // A slot one tile wide runs up from the bottom, its sides on the
+        // tiles x = 4 and x = 5 with nothing between them
+        let tiles = parse("0,0\n4,0\n4,5\n5,5\n5,0\n9,0\n9,9\n0,9\n").unwrap();
+        assert!(Floor::new(&tiles).contains(&rect(0, 0, 9, 9)));
+        assert_eq!(part2(&tiles), 100);
+    }
+
+    #[test]
+    fn test_floor_contains() {
+        let polygon = parse(EXAMPLE).unwrap();
 
         // Rectangle from 7,3 to 11,1 has corners: (7,1), (7,3), (11,1), (11,3)
         // Check each corner:
-        assert!(point_in_polygon((7, 1), &polygon), "corner (7,1) should be in");
-        assert!(point_in_polygon((7, 3), &polygon), "corner (7,3) should be in");
-        assert!(point_in_polygon((11, 1), &polygon), "corner (11,1) should be in");
-        assert!(point_in_polygon((11, 3), &polygon), "corner (11,3) should be in");
+        assert!(point_in_polygon(Point2::new(7, 1), &polygon), "corner (7,1) should be in");
+        assert!(point_in_polygon(Point2::new(7, 3), &polygon), "corner (7,3) should be in");
+        assert!(point_in_polygon(Point2::new(11, 1), &polygon), "corner (11,1) should be in");
+        assert!(point_in_polygon(Point2::new(11, 3), &polygon), "corner (11,3) should be in");
 
-        // Rectangle from 7,3 to 11,1 - check edges don't cross
+        // Rectangle from 7,3 to 11,1 - edges run along the polygon but don't cross it
         // Rectangle edges: bottom (7,1)-(11,1), top (7,3)-(11,3), left (7,1)-(7,3), right (11,1)-(11,3)
-        // Polygon edges check
-        let rect_edges = [
-            ((7i64, 1i64), (11i64, 1i64)), // bottom
-            ((7, 3), (11, 3)), // top
-            ((7, 1), (7, 3)), // left
-            ((11, 1), (11, 3)), // right
-        ];
-        for (idx, rect_edge) in rect_edges.iter().enumerate() {
-            for (pidx, i) in (0..polygon.len()).enumerate() {
-                let poly_edge = (polygon[i], polygon[(i + 1) % polygon.len()]);
-                if edges_cross_interior(rect_edge, &poly_edge) {
-                    println!("rect edge {} {:?} crosses poly edge {} {:?}", idx, rect_edge, pidx, poly_edge);
-                }
-            }
+        for rect_edge in rect(7, 3, 11, 1).edges() {
+            assert!(polygon_edges(&polygon).all(|poly_edge| !rect_edge.crosses(&poly_edge)), "{:?} crosses", rect_edge);
         }
 
+        let floor = Floor::new(&polygon);
+
         // Rectangle from 7,3 to 11,1 - should be valid (area 15)
-        assert!(is_rect_valid(&polygon, 7, 3, 11, 1), "7,3 to 11,1 should be valid");
+        assert!(floor.contains(&rect(7, 3, 11, 1)), "7,3 to 11,1 should be valid");
 
         // Rectangle from 9,5 to 2,3 - should be valid (area 24)
-        assert!(is_rect_valid(&polygon, 9, 5, 2, 3), "9,5 to 2,3 should be valid");
+        assert!(floor.contains(&rect(9, 5, 2, 3)), "9,5 to 2,3 should be valid");
 
         // Rectangle from 2,5 to 11,1 - should be INVALID (goes outside)
-        assert!(!is_rect_valid(&polygon, 2, 5, 11, 1), "2,5 to 11,1 should be invalid");
+        assert!(!floor.contains(&rect(2, 5, 11, 1)), "2,5 to 11,1 should be invalid");
     }
 
     #[test]
     fn test_point_in_polygon() {
-        let polygon: Vec<(i64, i64)> = vec![
-            (7, 1),
-            (11, 1),
-            (11, 7),
-            (9, 7),
-            (9, 5),
-            (2, 5),
-            (2, 3),
-            (7, 3),
-        ];
+        let polygon = parse(EXAMPLE).unwrap();
         // Red tiles should be in
-        assert!(point_in_polygon((7, 1), &polygon), "7,1 should be in");
-        assert!(point_in_polygon((11, 1), &polygon), "11,1 should be in");
-        assert!(point_in_polygon((2, 5), &polygon), "2,5 should be in");
+        assert!(point_in_polygon(Point2::new(7, 1), &polygon), "7,1 should be in");
+        assert!(point_in_polygon(Point2::new(11, 1), &polygon), "11,1 should be in");
+        assert!(point_in_polygon(Point2::new(2, 5), &polygon), "2,5 should be in");
 
         // Green tiles on edges should be in
-        assert!(point_in_polygon((8, 1), &polygon), "8,1 should be in (on edge)");
-        assert!(point_in_polygon((9, 1), &polygon), "9,1 should be in (on edge)");
+        assert!(point_in_polygon(Point2::new(8, 1), &polygon), "8,1 should be in (on edge)");
+        assert!(point_in_polygon(Point2::new(9, 1), &polygon), "9,1 should be in (on edge)");
 
         // Interior green tiles should be in
-        assert!(point_in_polygon((8, 2), &polygon), "8,2 should be in (interior)");
-        assert!(point_in_polygon((5, 4), &polygon), "5,4 should be in (interior)");
+        assert!(point_in_polygon(Point2::new(8, 2), &polygon), "8,2 should be in (interior)");
+        assert!(point_in_polygon(Point2::new(5, 4), &polygon), "5,4 should be in (interior)");
 
         // Outside tiles should be out
-        assert!(!point_in_polygon((1, 1), &polygon), "1,1 should be out");
-        assert!(!point_in_polygon((5, 1), &polygon), "5,1 should be out");
+        assert!(!point_in_polygon(Point2::new(1, 1), &polygon), "1,1 should be out");
+        assert!(!point_in_polygon(Point2::new(5, 1), &polygon), "5,1 should be out");
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let far = "coordinate of at most 100000000 either way";
+        assert_eq!(parse("0,0\n9223372036854775807,0"), Err(ParseError::new(2, 1, far)));
+        assert_eq!(parse("0,0\n1;2"), Err(ParseError::new(2, 1, "X,Y coordinates")));
     }
 }
@@ -1,57 +1,104 @@
-use std::fs;
+use std::collections::HashMap;
 
-struct Machine {
-    lights: Vec<bool>,
-    buttons: Vec<Vec<usize>>,
-    joltage: Vec<i64>,
+use crate::parse::{self, ParseError};
+use crate::solution::{Answer, Solution};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Machine>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, String> {
+        part1(input).map(Answer::from).ok_or_else(|| "no presses turn on some machine's lights".to_string())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, String> {
+        part2(input).map(Answer::from).ok_or_else(|| "no presses reach some machine's joltage".to_string())
+    }
+}
+
+pub struct Machine {
+    pub lights: Vec<bool>,
+    pub buttons: Vec<Vec<usize>>,
+    pub joltage: Vec<i64>,
 }
 
-fn parse(input: &str) -> Vec<Machine> {
+pub fn parse(input: &str) -> Result<Vec<Machine>, ParseError> {
     let mut machines = Vec::new();
 
     for line in input.lines() {
         if line.is_empty() {
             continue;
         }
+        let end_of_line = &line[line.len()..];
 
         // Parse indicator lights [.##.]
-        let bracket_start = line.find('[').unwrap();
-        let bracket_end = line.find(']').unwrap();
+        let bracket_start = line
+            .find('[')
+            .ok_or_else(|| ParseError::at(input, line, "'[' opening the indicator lights"))?;
+        let bracket_end = bracket_start
+            + line[bracket_start..]
+                .find(']')
+                .ok_or_else(|| ParseError::at(input, end_of_line, "']'"))?;
         let lights_str = &line[bracket_start + 1..bracket_end];
-        let lights: Vec<bool> = lights_str.chars().map(|c| c == '#').collect();
+        let lights: Vec<bool> = lights_str
+            .char_indices()
+            .map(|(i, c)| match c {
+                '#' => Ok(true),
+                '.' => Ok(false),
+                _ => Err(ParseError::at(input, &lights_str[i..], "light . or #")),
+            })
+            .collect::<Result<_, _>>()?;
+        if lights.len() > MAX_LIGHTS {
+            return Err(ParseError::at(input, lights_str, format!("at most {} lights", MAX_LIGHTS)));
+        }
 
         // Parse buttons (0,1,2) etc - everything in parentheses before the curly brace
-        let curly_start = line.find('{').unwrap();
-        let buttons_section = &line[bracket_end + 1..curly_start];
+        let curly_start = bracket_end
+            + line[bracket_end..]
+                .find('{')
+                .ok_or_else(|| ParseError::at(input, end_of_line, "'{' opening the joltage requirements"))?;
+        let mut buttons_section = &line[bracket_end + 1..curly_start];
 
         let mut buttons = Vec::new();
-        let mut i = 0;
-        let chars: Vec<char> = buttons_section.chars().collect();
-        while i < chars.len() {
-            if chars[i] == '(' {
-                let mut j = i + 1;
-                while chars[j] != ')' {
-                    j += 1;
-                }
-                let inner = &buttons_section[i + 1..j];
-                let indices: Vec<usize> = inner
-                    .split(',')
-                    .map(|s| s.trim().parse().unwrap())
-                    .collect();
-                buttons.push(indices);
-                i = j + 1;
-            } else {
-                i += 1;
+        while let Some(open) = buttons_section.find('(') {
+            let close = open
+                + buttons_section[open..]
+                    .find(')')
+                    .ok_or_else(|| ParseError::at(input, &line[curly_start..], "')'"))?;
+            if buttons.len() == MAX_BUTTONS {
+                return Err(ParseError::at(input, &buttons_section[open..], format!("at most {} buttons", MAX_BUTTONS)));
             }
+            let indices: Vec<usize> = buttons_section[open + 1..close]
+                .split(',')
+                .map(|s| light_index(input, s.trim(), lights.len()))
+                .collect::<Result<_, _>>()?;
+            if let Some(i) = (1..indices.len()).find(|&i| indices[..i].contains(&indices[i])) {
+                let repeated = buttons_section[open + 1..close].split(',').nth(i).unwrap_or_default();
+                return Err(ParseError::at(input, repeated.trim(), "a light not already on the button"));
+            }
+            buttons.push(indices);
+            buttons_section = &buttons_section[close + 1..];
         }
 
         // Parse joltage requirements {3,5,4,7}
-        let curly_end = line.find('}').unwrap();
+        let curly_end = curly_start
+            + line[curly_start..]
+                .find('}')
+                .ok_or_else(|| ParseError::at(input, end_of_line, "'}'"))?;
         let joltage_str = &line[curly_start + 1..curly_end];
         let joltage: Vec<i64> = joltage_str
             .split(',')
-            .map(|s| s.trim().parse().unwrap())
-            .collect();
+            .map(|s| joltage(input, s.trim()))
+            .collect::<Result<_, _>>()?;
+        if joltage.len() != lights.len() {
+            let expected = format!("{} joltage values, one per light", lights.len());
+            return Err(ParseError::at(input, joltage_str, expected));
+        }
 
         machines.push(Machine {
             lights,
@@ -60,10 +107,40 @@ fn parse(input: &str) -> Vec<Machine> {
         });
     }
 
-    machines
+    Ok(machines)
+}
+
+fn light_index(input: &str, s: &str, lights: usize) -> Result<usize, ParseError> {
+    let index = parse::number(input, s, "light index")?;
+    if index >= lights {
+        return Err(ParseError::at(input, s, format!("light index below {}", lights)));
+    }
+    Ok(index)
+}
+
+// Part 2 enumerates press counts up to the joltages, so huge ones are refused
+fn joltage(input: &str, s: &str) -> Result<i64, ParseError> {
+    let joltage = parse::number(input, s, "joltage")?;
+    if !(0..=MAX_JOLTAGE).contains(&joltage) {
+        return Err(ParseError::at(input, s, format!("joltage from 0 to {}", MAX_JOLTAGE)));
+    }
+    Ok(joltage)
+}
+
+const MAX_JOLTAGE: i64 = 1000;
+
+// Both parts try every set of buttons for some machines, and the lights they
+// toggle are kept as bits
+const MAX_LIGHTS: usize = 16;
+const MAX_BUTTONS: usize = 16;
+
+// Fewest presses that leave exactly the `target` lights on, or None if none do
+pub fn solve_machine(target: &[bool], buttons: &[Vec<usize>]) -> Option<usize> {
+    let presses = fewest_toggles(target, buttons);
+    (presses != usize::MAX).then_some(presses)
 }
 
-fn solve_machine(target: &[bool], buttons: &[Vec<usize>]) -> usize {
+fn fewest_toggles(target: &[bool], buttons: &[Vec<usize>]) -> usize {
     let n = target.len();
     let m = buttons.len();
 
@@ -200,8 +277,8 @@ fn solve_with_gauss(target: &[bool], buttons: &[Vec<usize>]) -> usize {
     min_presses
 }
 
-fn part1(input: &str) -> usize {
-    let machines = parse(input);
+// None if some machine can't be solved
+pub fn part1(machines: &[Machine]) -> Option<usize> {
     machines
         .iter()
         .map(|m| solve_machine(&m.lights, &m.buttons))
@@ -210,13 +287,15 @@ fn part1(input: &str) -> usize {
 
 // Solve Ax = b over integers with x >= 0, minimizing sum(x)
 // This is Integer Linear Programming, but with special structure:
-// A is 0/1 matrix, we want to minimize sum of button presses
-fn solve_joltage(buttons: &[Vec<usize>], target: &[i64]) -> i64 {
+// A is 0/1 matrix, we want to minimize sum of button presses. None if no
+// presses reach the target
+pub fn solve_joltage(buttons: &[Vec<usize>], target: &[i64]) -> Option<i64> {
     let n = target.len();
     let m = buttons.len();
 
     // Use Gaussian elimination + search
-    solve_joltage_gauss(buttons, target, n, m)
+    let presses = solve_joltage_gauss(buttons, target, n, m);
+    (presses != i64::MAX).then_some(presses)
 }
 
 fn solve_joltage_gauss(buttons: &[Vec<usize>], target: &[i64], n: usize, m: usize) -> i64 {
@@ -281,6 +360,11 @@ fn solve_joltage_gauss(buttons: &[Vec<usize>], target: &[i64], n: usize, m: usiz
     let pivot_set: std::collections::HashSet<usize> = pivot_cols.iter().cloned().collect();
     let free_cols: Vec<usize> = (0..m).filter(|c| !pivot_set.contains(c)).collect();
 
+    // Searching more free press counts than this takes too long
+    if free_cols.len() > 4 {
+        return solve_joltage_halving(buttons, target);
+    }
+
     if free_cols.is_empty() {
         // Unique solution
         let mut solution = vec![0i64; m];
@@ -323,197 +407,126 @@ fn solve_joltage_gauss(buttons: &[Vec<usize>], target: &[i64], n: usize, m: usiz
         null_scaled.push(null_vec);
     }
 
-    // Optimize using coordinate descent with smart bounds
-    let num_free = free_cols.len();
-    let mut best = i64::MAX;
-
-    // For small number of free variables, enumerate all valid combinations
-    if num_free <= 4 {
-        // Small enumeration
-        let max_val = target.iter().max().copied().unwrap_or(0);
-        let bound = (max_val + 50).max(300);
-
-        fn enumerate(
-            idx: usize,
-            t: &mut Vec<i64>,
-            part: &[i64],
-            null_vecs: &[Vec<i64>],
-            lcm: i64,
-            m: usize,
-            bound: i64,
-            best: &mut i64,
-        ) {
-            if idx == t.len() {
-                let val = eval_solution_fast(t, part, null_vecs, lcm, m);
-                if val < *best {
-                    *best = val;
-                }
-                return;
-            }
-            for ti in -bound..=bound {
-                t[idx] = ti;
-                enumerate(idx + 1, t, part, null_vecs, lcm, m, bound, best);
-            }
+    // A free variable is that button's press count, which can't go past the
+    // lowest joltage of any light it raises
+    let bounds: Vec<i64> = free_cols
+        .iter()
+        .map(|&col| buttons[col].iter().map(|&light| target[light]).min().unwrap_or(0))
+        .collect();
+    let mut search = FreeSearch {
+        bounds: &bounds,
+        part: &part_scaled,
+        null_vecs: &null_scaled,
+        lcm: lcm_denom,
+        best: i64::MAX,
+    };
+    search.run(&mut vec![0; free_cols.len()], 0);
+    search.best
+}
+
+// Branch and bound over the free press counts, fixing one at a time. A branch
+// is dropped once some button would need negative presses whatever the
+// remaining counts are, or once it can't beat the best total so far.
+struct FreeSearch<'a> {
+    bounds: &'a [i64],
+    part: &'a [i64],
+    null_vecs: &'a [Vec<i64>],
+    lcm: i64,
+    best: i64,
+}
+
+impl FreeSearch<'_> {
+    fn run(&mut self, t: &mut Vec<i64>, idx: usize) {
+        let m = self.part.len();
+        if idx == t.len() {
+            self.best = self.best.min(eval_solution_fast(t, self.part, self.null_vecs, self.lcm, m));
+            return;
         }
 
-        let mut t = vec![0i64; num_free];
-        enumerate(
-            0,
-            &mut t,
-            &part_scaled,
-            &null_scaled,
-            lcm_denom,
-            m,
-            bound,
-            &mut best,
-        );
-        return best;
-    }
-
-    // For larger number of free variables, use coordinate descent
-    let mut t = vec![0i64; num_free];
-
-    // First, find a valid starting point using greedy approach
-    for _ in 0..1000 {
-        let mut valid = true;
+        // Scaled presses of every button with the counts fixed so far, and
+        // the most the remaining counts could add to each
+        let mut lowest_total = 0;
         for j in 0..m {
-            let mut val = part_scaled[j];
-            for (i, &ti) in t.iter().enumerate() {
-                val += ti * null_scaled[i][j];
-            }
-            if val < 0 {
-                valid = false;
-                // Find which t[i] can help increase this value
-                for i in 0..num_free {
-                    if null_scaled[i][j] > 0 {
-                        t[i] += (-val / null_scaled[i][j]).max(1);
-                        break;
-                    } else if null_scaled[i][j] < 0 {
-                        t[i] -= (-val / (-null_scaled[i][j])).max(1);
-                        break;
-                    }
-                }
-                break;
+            let fixed = self.part[j] + (0..idx).map(|k| t[k] * self.null_vecs[k][j]).sum::<i64>();
+            let most = (idx..t.len()).map(|k| (self.null_vecs[k][j] * self.bounds[k]).max(0)).sum::<i64>();
+            if fixed + most < 0 {
+                return;
             }
+            lowest_total += fixed + (idx..t.len()).map(|k| (self.null_vecs[k][j] * self.bounds[k]).min(0)).sum::<i64>();
         }
-        if valid {
-            let val = eval_solution_fast(&t, &part_scaled, &null_scaled, lcm_denom, m);
-            if val < best {
-                best = val;
-            }
-            break;
+        if self.best != i64::MAX && lowest_total >= self.best * self.lcm {
+            return;
         }
-    }
 
-    // Local search - coordinate descent with proper bound computation
-    let mut improved = true;
-    let mut iterations = 0;
-    while improved && iterations < 100 {
-        improved = false;
-        iterations += 1;
-
-        for i in 0..num_free {
-            // Find valid range for t[i]
-            let mut min_t = i64::MIN / 2;
-            let mut max_t = i64::MAX / 2;
-
-            for j in 0..m {
-                let base = part_scaled[j]
-                    + t.iter()
-                        .enumerate()
-                        .filter(|(k, _)| *k != i)
-                        .map(|(k, &tk)| tk * null_scaled[k][j])
-                        .sum::<i64>();
-
-                let coef = null_scaled[i][j];
-                if coef > 0 {
-                    // base + coef * t[i] >= 0  =>  t[i] >= -base/coef
-                    // Use ceiling division: ceil(-base / coef)
-                    let bound = div_ceil(-base, coef);
-                    min_t = min_t.max(bound);
-                } else if coef < 0 {
-                    // base + coef * t[i] >= 0  =>  t[i] <= -base/coef (since coef < 0, inequality flips)
-                    // Use floor division: floor(-base / coef)
-                    let bound = div_floor(-base, coef);
-                    max_t = max_t.min(bound);
-                } else if base < 0 {
-                    min_t = 1;
-                    max_t = 0;
-                }
-            }
-
-            if min_t > max_t {
-                continue;
-            }
-
-            // The objective changes linearly with t[i]
-            let deriv: i64 = null_scaled[i].iter().sum();
-
-            let candidates = if deriv > 0 {
-                vec![min_t]
-            } else if deriv < 0 {
-                vec![max_t]
-            } else {
-                vec![min_t, max_t, t[i]]
-            };
-
-            for &candidate in &candidates {
-                if candidate >= min_t && candidate <= max_t {
-                    let old_ti = t[i];
-                    t[i] = candidate;
-                    let val = eval_solution_fast(&t, &part_scaled, &null_scaled, lcm_denom, m);
-                    if val < best {
-                        best = val;
-                        improved = true;
-                    } else {
-                        t[i] = old_ti;
-                    }
-                }
-            }
+        for ti in 0..=self.bounds[idx] {
+            t[idx] = ti;
+            self.run(t, idx + 1);
         }
+        t[idx] = 0;
     }
+}
 
-    // Also try from multiple starting points
-    for start in 0..20 {
-        let mut t2 = vec![0i64; num_free];
-        for i in 0..num_free {
-            t2[i] = (start as i64 * 17 + i as i64 * 31) % 201 - 100;
+// The buttons pressed an odd number of times are the ones that fix the parity
+// of every light, and the other presses come in pairs, which solve half of
+// what's left. So the fewest presses are the best, over sets of buttons with
+// the right parity, of the set's size plus twice the fewest presses for the
+// halved remainder. Every set is tried, so this suits machines with many more
+// buttons than lights.
+fn solve_joltage_halving(buttons: &[Vec<usize>], target: &[i64]) -> i64 {
+    let sets = button_sets(buttons, target);
+
+    fn fewest(left: &[i64], sets: &HashMap<u64, Vec<(i64, Vec<i64>)>>, memo: &mut HashMap<Vec<i64>, i64>) -> i64 {
+        if left.iter().all(|&j| j == 0) {
+            return 0;
         }
-
-        // Try to make it valid
-        for _ in 0..100 {
-            let mut all_valid = true;
-            for j in 0..m {
-                let mut val = part_scaled[j];
-                for (i, &ti) in t2.iter().enumerate() {
-                    val += ti * null_scaled[i][j];
-                }
-                if val < 0 {
-                    all_valid = false;
-                    for i in 0..num_free {
-                        if null_scaled[i][j] > 0 {
-                            t2[i] += 1;
-                            break;
-                        } else if null_scaled[i][j] < 0 {
-                            t2[i] -= 1;
-                            break;
-                        }
-                    }
-                    break;
+        if let Some(&known) = memo.get(left) {
+            return known;
+        }
+        let parity = left.iter().enumerate().fold(0, |p, (light, &j)| p | ((j as u64 & 1) << light));
+        let mut best = i64::MAX;
+        for (size, added) in sets.get(&parity).into_iter().flatten() {
+            if added.iter().zip(left).all(|(a, j)| a <= j) {
+                let half: Vec<i64> = left.iter().zip(added).map(|(j, a)| (j - a) / 2).collect();
+                let rest = fewest(&half, sets, memo);
+                if rest != i64::MAX {
+                    best = best.min(size + 2 * rest);
                 }
             }
-            if all_valid {
-                break;
-            }
         }
+        memo.insert(left.to_vec(), best);
+        best
+    }
 
-        let val = eval_solution_fast(&t2, &part_scaled, &null_scaled, lcm_denom, m);
-        if val < best {
-            best = val;
+    fewest(target, &sets, &mut HashMap::new())
+}
+
+// The fewest buttons adding each joltage that stays within `target`, grouped
+// by the lights left odd. What's left only shrinks as it's halved, so a set
+// that overshoots the target is never used, and neither is any set holding
+// it: those aren't built, which keeps low joltages from costing every set.
+fn button_sets(buttons: &[Vec<usize>], target: &[i64]) -> HashMap<u64, Vec<(i64, Vec<i64>)>> {
+    let mut fewest: HashMap<Vec<i64>, i64> = HashMap::new();
+    // Sets still to extend, by the first button they may add, their size and
+    // the joltage they add
+    let mut stack = vec![(0, 0, vec![0; target.len()])];
+    while let Some((first, size, added)) = stack.pop() {
+        for (i, button) in buttons.iter().enumerate().skip(first) {
+            if button.iter().all(|&light| added[light] < target[light]) {
+                let mut more = added.clone();
+                button.iter().for_each(|&light| more[light] += 1);
+                stack.push((i + 1, size + 1, more));
+            }
         }
+        let known = fewest.entry(added).or_insert(size);
+        *known = (*known).min(size);
     }
 
-    best
+    let mut sets: HashMap<u64, Vec<(i64, Vec<i64>)>> = HashMap::new();
+    for (added, size) in fewest {
+        let parity = added.iter().enumerate().fold(0, |p, (light, &a)| p | ((a as u64 & 1) << light));
+        sets.entry(parity).or_default().push((size, added));
+    }
+    sets
 }
 
 fn eval_solution_fast(t: &[i64], part: &[i64], null_vecs: &[Vec<i64>], lcm: i64, m: usize) -> i64 {
@@ -547,55 +560,14 @@ fn lcm(a: i64, b: i64) -> i64 {
     }
 }
 
-// Ceiling division: ceil(a / b) where b > 0
-fn div_ceil(a: i64, b: i64) -> i64 {
-    assert!(b > 0);
-    if a >= 0 {
-        (a + b - 1) / b
-    } else {
-        // For negative a, Rust's / truncates toward zero, which is ceiling
-        a / b
-    }
-}
-
-// Floor division: floor(a / b) where b < 0
-fn div_floor(a: i64, b: i64) -> i64 {
-    assert!(b < 0);
-    // a / b where b < 0
-    // We want floor division (toward negative infinity)
-    // Rust truncates toward zero
-
-    // Convert to positive divisor problem
-    // floor(a / b) where b < 0 = floor(a / b)
-    // = -ceil(a / (-b)) = -ceil(-a / b) for b > 0... this gets confusing
-
-    // Let's just compute it directly
-    let quot = a / b;
-    let rem = a % b;
-    // If there's a remainder and the signs of a and b are different, we need to adjust
-    // Actually for floor division with negative b:
-    // If a and b have different signs and there's a remainder, subtract 1
-    if rem != 0 && (a > 0) {
-        quot - 1
-    } else {
-        quot
-    }
-}
-
-fn part2(input: &str) -> i64 {
-    let machines = parse(input);
+// None if some machine can't be solved
+pub fn part2(machines: &[Machine]) -> Option<i64> {
     machines
         .iter()
         .map(|m| solve_joltage(&m.buttons, &m.joltage))
         .sum()
 }
 
-fn main() {
-    let input = fs::read_to_string("data/10.txt").expect("Could not read input file");
-    println!("part1: {}", part1(&input));
-    println!("part2: {}", part2(&input));
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -606,53 +578,105 @@ mod tests {
 
     #[test]
     fn test_part1() {
-        assert_eq!(part1(EXAMPLE), 7);
+        assert_eq!(part1(&parse(EXAMPLE).unwrap()), Some(7));
     }
 
     #[test]
     fn test_machine1() {
-        let machines = parse("[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}");
+        let machines = parse("[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}").unwrap();
         let m = &machines[0];
-        assert_eq!(solve_machine(&m.lights, &m.buttons), 2);
+        assert_eq!(solve_machine(&m.lights, &m.buttons), Some(2));
     }
 
     #[test]
     fn test_machine2() {
-        let machines = parse("[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}");
+        let machines = parse("[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}").unwrap();
         let m = &machines[0];
-        assert_eq!(solve_machine(&m.lights, &m.buttons), 3);
+        assert_eq!(solve_machine(&m.lights, &m.buttons), Some(3));
     }
 
     #[test]
     fn test_machine3() {
-        let machines = parse("[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}");
+        let machines = parse("[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}").unwrap();
         let m = &machines[0];
-        assert_eq!(solve_machine(&m.lights, &m.buttons), 2);
+        assert_eq!(solve_machine(&m.lights, &m.buttons), Some(2));
     }
 
     #[test]
     fn test_part2() {
-        assert_eq!(part2(EXAMPLE), 33);
+        assert_eq!(part2(&parse(EXAMPLE).unwrap()), Some(33));
     }
 
     #[test]
     fn test_joltage1() {
-        let machines = parse("[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}");
+        let machines = parse("[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}").unwrap();
         let m = &machines[0];
-        assert_eq!(solve_joltage(&m.buttons, &m.joltage), 10);
+        assert_eq!(solve_joltage(&m.buttons, &m.joltage), Some(10));
     }
 
     #[test]
     fn test_joltage2() {
-        let machines = parse("[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}");
+        let machines = parse("[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}").unwrap();
         let m = &machines[0];
-        assert_eq!(solve_joltage(&m.buttons, &m.joltage), 12);
+        assert_eq!(solve_joltage(&m.buttons, &m.joltage), Some(12));
     }
 
     #[test]
     fn test_joltage3() {
-        let machines = parse("[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}");
+        let machines = parse("[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}").unwrap();
         let m = &machines[0];
-        assert_eq!(solve_joltage(&m.buttons, &m.joltage), 11);
+        assert_eq!(solve_joltage(&m.buttons, &m.joltage), Some(11));
+    }
+
+    #[test]
+    fn test_part2_more_buttons_than_lights() {
+        let machines = parse("[....] (1,2,3) (0,1,2) (0,1) (1) (0,1,2,3) (1,2) (3) (0,3) (2) (0) (0,2,3) {8,6,7,4}").unwrap();
+        assert_eq!(part2(&machines), Some(8));
+    }
+
+    #[test]
+    fn test_joltage_halving() {
+        for (line, expected) in EXAMPLE.lines().zip([10, 12, 11]) {
+            let m = &parse(line).unwrap()[0];
+            assert_eq!(solve_joltage_halving(&m.buttons, &m.joltage), expected);
+        }
+        let m = &parse("[.#] (1) {1,1}").unwrap()[0];
+        assert_eq!(solve_joltage_halving(&m.buttons, &m.joltage), i64::MAX);
+    }
+
+    #[test]
+    fn test_button_sets() {
+        let buttons = [vec![0], vec![0, 1], vec![1]];
+        let sets = button_sets(&buttons, &[1, 2]);
+        // Buttons 0 and 2 add as much as button 1 alone, so only that is kept
+        assert_eq!(sets[&0b11], vec![(1, vec![1, 1])]);
+        // Anything adding two to the first light overshoots
+        assert!(sets.values().flatten().all(|(_, added)| added[0] <= 1));
+        assert_eq!(sets.values().map(Vec::len).sum::<usize>(), 5);
+    }
+
+    #[test]
+    fn test_unsolvable() {
+        let lights = parse("[#.] (1) {0,1}").unwrap();
+        assert_eq!(part1(&lights), None);
+        assert_eq!(part2(&lights), Some(1));
+        let joltage = parse("[.#] (1) {1,1}").unwrap();
+        assert_eq!(part1(&joltage), Some(1));
+        assert_eq!(part2(&joltage), None);
+        assert_eq!(Day10::part2(&joltage), Err("no presses reach some machine's joltage".to_string()));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = |input| parse(input).err().map(|e: ParseError| (e.column, e.expected));
+        assert_eq!(err(".##.] (3) {1,2,3,4}"), Some((1, "'[' opening the indicator lights".into())));
+        assert_eq!(err("[.##.] (3 {1,2,3,4}"), Some((11, "')'".into())));
+        assert_eq!(err("[.##.] (4) {1,2,3,4}"), Some((9, "light index below 4".into())));
+        assert_eq!(err("[.##.] (3) {1,2,3}"), Some((13, "4 joltage values, one per light".into())));
+        assert_eq!(err("[.##.] (3)"), Some((11, "'{' opening the joltage requirements".into())));
+        assert_eq!(err("[.##.] (1,3,1) {1,2,3,4}"), Some((13, "a light not already on the button".into())));
+        assert_eq!(err("[.##.] (3) {1,2,3,4000}"), Some((19, "joltage from 0 to 1000".into())));
+        let many = format!("[.] {}{{1}}", "(0) ".repeat(17));
+        assert_eq!(parse(&many).err(), Some(ParseError::new(1, 69, "at most 16 buttons")));
     }
 }
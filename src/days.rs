@@ -0,0 +1,71 @@
+use std::any::Any;
+
+use aoc2025::parse::ParseError;
+use aoc2025::solution::{Answer, Solution};
+use aoc2025::{day01, day02, day03, day04, day05, day06, day07, day08, day09, day10};
+
+// A solved day as seen by the runner. The parsed input is type-erased so days
+// with different `Solution::Input` types can share one table. Adding a day is
+// one module in lib.rs plus one entry in DAYS.
+pub struct Day {
+    pub number: u32,
+    parse: fn(&str) -> Result<Box<dyn Any>, ParseError>,
+    part1: fn(&dyn Any) -> Result<Answer, String>,
+    part2: fn(&dyn Any) -> Result<Answer, String>,
+}
+
+impl Day {
+    const fn of<S: Solution>(number: u32) -> Self
+    where
+        S::Input: 'static,
+    {
+        Day {
+            number,
+            parse: |input| Ok(Box::new(S::parse(input)?)),
+            part1: |input| S::part1(input.downcast_ref().expect("input parsed by another day")),
+            part2: |input| S::part2(input.downcast_ref().expect("input parsed by another day")),
+        }
+    }
+
+    pub fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError> {
+        (self.parse)(input)
+    }
+
+    pub fn part1(&self, input: &dyn Any) -> Result<Answer, String> {
+        (self.part1)(input)
+    }
+
+    pub fn part2(&self, input: &dyn Any) -> Result<Answer, String> {
+        (self.part2)(input)
+    }
+
+    // The answer, or why the part has none.
+    pub fn solve(&self, input: &dyn Any, part: u8) -> Result<Answer, String> {
+        match part {
+            1 => self.part1(input),
+            2 => self.part2(input),
+            _ => panic!("there is no part {}", part),
+        }
+    }
+
+    pub fn input_path(&self) -> String {
+        format!("data/{:02}.txt", self.number)
+    }
+}
+
+pub const DAYS: &[Day] = &[
+    Day::of::<day01::Day01>(1),
+    Day::of::<day02::Day02>(2),
+    Day::of::<day03::Day03>(3),
+    Day::of::<day04::Day04>(4),
+    Day::of::<day05::Day05>(5),
+    Day::of::<day06::Day06>(6),
+    Day::of::<day07::Day07>(7),
+    Day::of::<day08::Day08>(8),
+    Day::of::<day09::Day09>(9),
+    Day::of::<day10::Day10>(10),
+];
+
+pub fn find(number: u32) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
@@ -0,0 +1,87 @@
+use std::fs;
+use std::path::Path;
+
+use aoc2025::{html, task};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Saved {
+    Written,
+    Unchanged,
+    // Already had different, non-empty contents; left alone.
+    Kept,
+}
+
+// Writes tasks/dayNN-1.md and, once the page has it, tasks/dayNN-2.md from a
+// puzzle page. Existing descriptions are only replaced with `force`; the empty
+// placeholders from `aoc new` always are.
+pub fn save_tasks(root: &Path, day: u32, page: &str, force: bool) -> Result<Vec<(String, Saved)>, String> {
+    let articles = html::articles(page);
+    if articles.is_empty() {
+        return Err(format!("no puzzle description in the day {} page", day));
+    }
+
+    let mut saved = Vec::new();
+    for (part, markdown) in (1..=2).zip(articles) {
+        let path = task::path(day, part);
+        let full = root.join(&path);
+        let existing = fs::read_to_string(&full).unwrap_or_default();
+        let outcome = if existing == markdown {
+            Saved::Unchanged
+        } else if !existing.trim().is_empty() && !force {
+            Saved::Kept
+        } else {
+            if let Some(dir) = full.parent() {
+                fs::create_dir_all(dir).map_err(|e| format!("{}: {}", dir.display(), e))?;
+            }
+            fs::write(&full, &markdown).map_err(|e| format!("{}: {}", path, e))?;
+            Saved::Written
+        };
+        saved.push((path, outcome));
+    }
+    Ok(saved)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::stub;
+    use crate::site::{self, tests::scratch};
+
+    const PART1: &str = "<article><h2>--- Day 3: Lobby ---</h2><p>Answer <code><em>357</em></code>.</p></article>";
+    const PART2: &str = "<article><h2>--- Part Two ---</h2><p>Now <code><em>3121</em></code>.</p></article>";
+
+    #[test]
+    fn test_save_tasks() {
+        let root = scratch("describe");
+        let saved = save_tasks(&root, 3, PART1, false).unwrap();
+        assert_eq!(saved, [("tasks/day03-1.md".to_string(), Saved::Written)]);
+        assert_eq!(
+            fs::read_to_string(root.join("tasks/day03-1.md")).unwrap(),
+            "## \\--- Day 3: Lobby ---\n\nAnswer `_357_`."
+        );
+
+        // Once part 2 unlocks, part 1 is already there.
+        let both = format!("{}<p>Your puzzle answer was 357.</p>{}", PART1, PART2);
+        let saved = save_tasks(&root, 3, &both, false).unwrap();
+        assert_eq!(saved[0].1, Saved::Unchanged);
+        assert_eq!(saved[1], ("tasks/day03-2.md".to_string(), Saved::Written));
+
+        fs::write(root.join("tasks/day03-1.md"), "hand edited").unwrap();
+        assert_eq!(save_tasks(&root, 3, PART1, false).unwrap()[0].1, Saved::Kept);
+        assert_eq!(save_tasks(&root, 3, PART1, true).unwrap()[0].1, Saved::Written);
+
+        assert!(save_tasks(&root, 3, "<html></html>", false).is_err());
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_fetched_page() {
+        let root = scratch("describe-fetch");
+        let server = stub::serve(vec![(200, PART1)]);
+        let page = site::fetch_page(&site::tests::site(&server.url, &root), 3).unwrap();
+        save_tasks(&root, 3, &page, false).unwrap();
+        assert!(server.requests()[0].starts_with("GET /2025/day/3 HTTP/1.0\r\n"));
+        assert_eq!(task::answer(&fs::read_to_string(root.join("tasks/day03-1.md")).unwrap()).as_deref(), Some("357"));
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
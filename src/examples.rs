@@ -0,0 +1,56 @@
+use aoc2025::task;
+
+use crate::check::{self, Check};
+use crate::days::Day;
+
+// Runs both parts on the example from the day's task files.
+pub fn check_day(day: &Day) -> Result<Vec<Check>, String> {
+    let example = task::load_example(day.number)?;
+    let source = task::path(day.number, 1);
+    Ok(check::check(day, &example.input, &source, [example.part1, example.part2]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::DAYS;
+
+    // Parts whose code deliberately disagrees with the task file's example.
+    // Day 8 part 1 makes 1000 connections where the example makes 10.
+    const KNOWN_MISMATCHES: &[(u32, u8)] = &[(8, 1)];
+
+    #[test]
+    fn test_examples() {
+        let mut failures = Vec::new();
+        for day in DAYS {
+            let checks = match check_day(day) {
+                Ok(checks) => checks,
+                // A day fresh from `aoc new` has an empty task file and no
+                // example yet.
+                Err(_) if is_scaffolded(day) => continue,
+                Err(e) => {
+                    failures.push(format!("day {}: {}", day.number, e));
+                    continue;
+                }
+            };
+            for check in checks {
+                let known = KNOWN_MISMATCHES.contains(&(check.day, check.part));
+                if check.passed() == known {
+                    failures.push(format!(
+                        "day {} part {}: expected {:?}, got {:?}{}",
+                        check.day,
+                        check.part,
+                        check.expected,
+                        check.actual,
+                        if known { " (listed as a known mismatch)" } else { "" }
+                    ));
+                }
+            }
+        }
+        assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+    }
+
+    fn is_scaffolded(day: &Day) -> bool {
+        std::fs::read_to_string(task::path(day.number, 1)).is_ok_and(|text| text.trim().is_empty())
+    }
+}
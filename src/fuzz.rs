@@ -0,0 +1,199 @@
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use aoc2025::random::Rng;
+use aoc2025::task;
+
+use crate::check::catch_panic;
+use crate::days::Day;
+use crate::{generate, oracle};
+
+// Mutation fuzzing for every day's parser and solvers. Starting from the
+// example and a few generated inputs, each case breaks an input a little and
+// requires that parsing either fails with an error or succeeds, and that an
+// input which parses solves without panicking. A case that runs past the
+// timeout counts as a hang.
+
+// Fragments the mutations splice in: each day's punctuation, numbers at and
+// just past the limits of the integer types, and some odd characters.
+const TOKENS: &[&str] = &[
+    "0", "1", "-1", "9", "-", ",", ".", "#", "@", "^", "S", "+", "*", "L", "R", "(", ")", "[", "]", "{", "}",
+    " ", "\n", "\n\n", "\t", "\r\n", "é", "\u{0}", "2147483647", "2147483648", "4294967296", "9999999999",
+    "9223372036854775807", "9223372036854775808", "18446744073709551615", "18446744073709551616",
+    "99999999999999999999999",
+];
+
+// Parse is always run; solving only once parsing succeeds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Target {
+    Parse,
+    Solve,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Failure {
+    Panic(String),
+    Hang,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Found {
+    pub input: String,
+    pub failure: Failure,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Report {
+    pub runs: usize,
+    // Inputs the parser turned down with an error.
+    pub rejected: usize,
+    pub found: Option<Found>,
+}
+
+// The example and a few small generated inputs.
+pub fn corpus(day: &Day) -> Vec<String> {
+    let mut corpus = Vec::new();
+    if let Ok(example) = task::load_example(day.number) {
+        corpus.push(example.input);
+    }
+    for seed in 0..3 {
+        if let Ok(input) = generate::generate(day.number, seed, Some(4)) {
+            corpus.push(input);
+        }
+    }
+    corpus
+}
+
+// A few random edits: cut a span, splice in a token, repeat a span, or
+// truncate.
+pub fn mutate(rng: &mut Rng, input: &str) -> String {
+    let mut chars: Vec<char> = input.chars().collect();
+    for _ in 0..rng.range(1, 4) {
+        let len = chars.len();
+        let span = |rng: &mut Rng| {
+            let start = rng.index(len);
+            (start, (start + rng.range(1, 12) as usize).min(len))
+        };
+        match rng.range(0, 4) {
+            0 if len > 0 => {
+                let (start, end) = span(rng);
+                chars.drain(start..end);
+            }
+            1 => {
+                let at = rng.index(len + 1);
+                chars.splice(at..at, rng.pick(TOKENS).chars());
+            }
+            2 if len > 0 => {
+                let (start, end) = span(rng);
+                let copy = chars[start..end].to_vec();
+                let at = rng.index(len + 1);
+                chars.splice(at..at, copy);
+            }
+            3 if len > 0 => chars.truncate(rng.index(len)),
+            _ => {}
+        }
+    }
+    chars.into_iter().collect()
+}
+
+// Runs one input, on a thread of its own so a hang can be abandoned. Ok(true)
+// if the input parsed.
+pub fn run_one(day: &'static Day, target: Target, input: &str, timeout: Duration) -> Result<bool, Failure> {
+    let (sender, receiver) = mpsc::channel();
+    let input = input.to_string();
+    thread::spawn(move || {
+        let result = catch_panic(|| match day.parse(&input) {
+            Ok(parsed) => {
+                // A part may fail on a valid input, but not panic.
+                if target == Target::Solve {
+                    let _ = day.part1(parsed.as_ref());
+                    let _ = day.part2(parsed.as_ref());
+                }
+                true
+            }
+            Err(_) => false,
+        });
+        let _ = sender.send(result);
+    });
+    match receiver.recv_timeout(timeout) {
+        Ok(result) => result.map_err(Failure::Panic),
+        Err(_) => Err(Failure::Hang),
+    }
+}
+
+// `runs` mutated inputs, stopping at the first failure. Panics are shrunk
+// before they are reported; hangs are too slow to shrink. None if the day has
+// neither an example nor a generator to start from.
+pub fn fuzz(day: &'static Day, target: Target, seed: u64, runs: usize, timeout: Duration) -> Option<Report> {
+    let corpus = corpus(day);
+    if corpus.is_empty() {
+        return None;
+    }
+    let mut rng = Rng::new(seed);
+    let mut report = Report::default();
+    for _ in 0..runs {
+        let mut input = rng.pick(&corpus).clone();
+        // Mutations stack, so some inputs end up far from the corpus.
+        for _ in 0..rng.range(1, 3) {
+            input = mutate(&mut rng, &input);
+        }
+        report.runs += 1;
+        match run_one(day, target, &input, timeout) {
+            Ok(true) => {}
+            Ok(false) => report.rejected += 1,
+            Err(Failure::Hang) => {
+                report.found = Some(Found {
+                    input,
+                    failure: Failure::Hang,
+                });
+                break;
+            }
+            Err(Failure::Panic(_)) => {
+                let panics = |candidate: &str| matches!(run_one(day, target, candidate, timeout), Err(Failure::Panic(_)));
+                let input = oracle::shrink(&input, panics);
+                let failure = run_one(day, target, &input, timeout).expect_err("shrinking keeps the panic");
+                report.found = Some(Found { input, failure });
+                break;
+            }
+        }
+    }
+    Some(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::{self, DAYS};
+
+    #[test]
+    fn test_mutate() {
+        let mut rng = Rng::new(1);
+        let input = "L68\nL30\nR48\n";
+        let mutants: Vec<String> = (0..50).map(|_| mutate(&mut rng, input)).collect();
+        assert!(mutants.iter().any(|m| m.len() < input.len()));
+        assert!(mutants.iter().any(|m| m.len() > input.len()));
+        assert!(mutants.iter().filter(|m| *m == input).count() < 10);
+        assert_eq!(mutate(&mut Rng::new(9), ""), mutate(&mut Rng::new(9), ""));
+    }
+
+    #[test]
+    fn test_run_one() {
+        let day = days::find(1).unwrap();
+        let timeout = Duration::from_secs(10);
+        assert_eq!(run_one(day, Target::Solve, "L68\nR2\n", timeout), Ok(true));
+        assert_eq!(run_one(day, Target::Solve, "X68\n", timeout), Ok(false));
+    }
+
+    #[test]
+    fn test_every_day() {
+        for day in DAYS {
+            for target in [Target::Parse, Target::Solve] {
+                // A day fresh from `aoc new` has nothing to mutate yet.
+                let Some(report) = fuzz(day, target, 0, 150, Duration::from_secs(20)) else { continue };
+                assert_eq!(report.found, None, "day {} {:?}", day.number, target);
+                assert!(report.rejected > 0 && report.rejected < report.runs, "day {}: {:?}", day.number, report);
+            }
+        }
+    }
+}
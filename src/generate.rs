@@ -0,0 +1,310 @@
+use aoc2025::random::Rng;
+
+// Random puzzle inputs in each day's format, for stress-testing the solvers
+// beyond the examples and our one real input. The same day, seed and size
+// always give the same input.
+struct Generator {
+    day: u32,
+    // Roughly the size of the real input, in the generator's own unit.
+    default_size: usize,
+    generate: fn(&mut Rng, usize) -> String,
+}
+
+const GENERATORS: &[Generator] = &[
+    Generator { day: 1, default_size: 4000, generate: day01 },
+    Generator { day: 2, default_size: 35, generate: day02 },
+    Generator { day: 3, default_size: 200, generate: day03 },
+    Generator { day: 4, default_size: 135, generate: day04 },
+    Generator { day: 5, default_size: 180, generate: day05 },
+    Generator { day: 6, default_size: 1000, generate: day06 },
+    Generator { day: 7, default_size: 141, generate: day07 },
+    Generator { day: 8, default_size: 1000, generate: day08 },
+    Generator { day: 9, default_size: 120, generate: day09 },
+    Generator { day: 10, default_size: 180, generate: day10 },
+];
+
+pub fn generate(day: u32, seed: u64, size: Option<usize>) -> Result<String, String> {
+    let generator = GENERATORS
+        .iter()
+        .find(|g| g.day == day)
+        .ok_or_else(|| format!("no generator for day {}", day))?;
+    let size = size.unwrap_or(generator.default_size);
+    if size == 0 {
+        return Err("--size must be at least 1".to_string());
+    }
+    Ok((generator.generate)(&mut Rng::new(seed), size))
+}
+
+fn lines(lines: impl IntoIterator<Item = String>) -> String {
+    lines.into_iter().map(|line| line + "\n").collect()
+}
+
+// `size` rotations.
+fn day01(rng: &mut Rng, size: usize) -> String {
+    lines((0..size).map(|_| format!("{}{}", rng.pick(&['L', 'R']), rng.range(1, 999))))
+}
+
+// `size` ranges of IDs up to ten digits, some of them crossing into the next
+// digit count.
+fn day02(rng: &mut Rng, size: usize) -> String {
+    let ranges: Vec<String> = (0..size)
+        .map(|_| {
+            let digits = rng.range(1, 10) as u32;
+            let start = rng.range(10u64.pow(digits - 1), 10u64.pow(digits) - 1);
+            let end = (start + rng.range(0, 10u64.pow(digits / 2 + 1))).min(9_999_999_999);
+            format!("{}-{}", start, end)
+        })
+        .collect();
+    ranges.join(",") + "\n"
+}
+
+// `size` banks of 12 to 100 batteries.
+fn day03(rng: &mut Rng, size: usize) -> String {
+    lines((0..size).map(|_| {
+        let len = rng.range(12, 100);
+        (0..len).map(|_| char::from(b'0' + rng.range(1, 9) as u8)).collect()
+    }))
+}
+
+// A `size` by `size` grid, about two thirds rolls.
+fn day04(rng: &mut Rng, size: usize) -> String {
+    lines((0..size).map(|_| (0..size).map(|_| if rng.chance(0.65) { '@' } else { '.' }).collect()))
+}
+
+// `size` possibly overlapping ranges and five times as many IDs, half of them
+// drawn from inside a range.
+fn day05(rng: &mut Rng, size: usize) -> String {
+    let ranges: Vec<(u64, u64)> = (0..size)
+        .map(|_| {
+            let start = rng.range(1, 500_000_000_000_000);
+            (start, start + rng.range(0, 10_000_000_000_000))
+        })
+        .collect();
+    let ids = (0..size * 5).map(|_| {
+        if rng.chance(0.5) {
+            let &(start, end) = rng.pick(&ranges);
+            rng.range(start, end)
+        } else {
+            rng.range(1, 510_000_000_000_000)
+        }
+    });
+    let ranges = ranges.iter().map(|(start, end)| format!("{}-{}", start, end));
+    format!("{}\n{}", lines(ranges), lines(ids.map(|id| id.to_string())))
+}
+
+// `size` problems of four numbers with up to four digits each. Every problem
+// is as wide as its longest number, with its numbers all aligned left or all
+// aligned right, and one blank column between problems.
+fn day06(rng: &mut Rng, size: usize) -> String {
+    const ROWS: usize = 4;
+    let mut rows = vec![Vec::new(); ROWS + 1];
+    for _ in 0..size {
+        let numbers: Vec<String> = (0..ROWS)
+            .map(|_| {
+                let digits = rng.range(1, 4) as u32;
+                rng.range(10u64.pow(digits - 1), 10u64.pow(digits) - 1).to_string()
+            })
+            .collect();
+        let width = numbers.iter().map(String::len).max().unwrap_or(1);
+        let left = rng.chance(0.5);
+        for (row, number) in rows.iter_mut().zip(&numbers) {
+            row.push(if left {
+                format!("{:<width$}", number)
+            } else {
+                format!("{:>width$}", number)
+            });
+        }
+        rows[ROWS].push(format!("{:<width$}", rng.pick(&['+', '*'])));
+    }
+    lines(rows.into_iter().map(|row| row.join(" ")))
+}
+
+// A `size` wide manifold with S in the middle of the top row. Splitters sit on
+// every other row, only where a beam could reach them, like the real input.
+fn day07(rng: &mut Rng, size: usize) -> String {
+    let width = size;
+    let middle = width / 2;
+    let mut grid = vec![vec!['.'; width]; width + 1];
+    grid[0][middle] = 'S';
+    for row in (2..=width).step_by(2) {
+        let reach = row / 2 - 1;
+        for offset in (0..=2 * reach).step_by(2) {
+            let Some(col) = (middle + offset).checked_sub(reach) else { continue };
+            if col < width && rng.chance(0.6) {
+                grid[row][col] = '^';
+            }
+        }
+    }
+    lines(grid.into_iter().map(|row| row.into_iter().collect()))
+}
+
+// `size` distinct junction boxes. Part 1 joins the 1000 closest pairs and
+// needs three circuits left afterwards, so small sizes only suit part 2.
+fn day08(rng: &mut Rng, size: usize) -> String {
+    let mut seen = std::collections::HashSet::new();
+    let mut boxes = Vec::new();
+    while boxes.len() < size {
+        let point = (rng.range(0, 99_999), rng.range(0, 99_999), rng.range(0, 99_999));
+        if seen.insert(point) {
+            boxes.push(point);
+        }
+    }
+    lines(boxes.into_iter().map(|(x, y, z)| format!("{},{},{}", x, y, z)))
+}
+
+// A closed rectilinear polygon of `size` side-by-side columns, each spanning a
+// shared middle line, so 4 * `size` corners in order. Neighbouring columns
+// never line up, so consecutive corners always turn. Transposed half of the
+// time.
+fn day09(rng: &mut Rng, size: usize) -> String {
+    const MIDDLE: u64 = 50_000;
+    // Packed close, columns are often two tiles wide with their sides on
+    // adjacent tiles
+    let widest = if rng.chance(0.5) { 99_999 } else { 2 * size as u64 + 2 };
+    let xs = rng.distinct(size + 1, 1, widest);
+    let mut columns: Vec<(u64, u64)> = Vec::with_capacity(size);
+    for i in 0..size {
+        loop {
+            let column = (rng.range(1, MIDDLE - 1), rng.range(MIDDLE + 1, 99_999));
+            if i == 0 || (column.0 != columns[i - 1].0 && column.1 != columns[i - 1].1) {
+                columns.push(column);
+                break;
+            }
+        }
+    }
+
+    let mut corners = Vec::with_capacity(4 * size);
+    for (i, &(_, top)) in columns.iter().enumerate() {
+        corners.push((xs[i], top));
+        corners.push((xs[i + 1], top));
+    }
+    for (i, &(bottom, _)) in columns.iter().enumerate().rev() {
+        corners.push((xs[i + 1], bottom));
+        corners.push((xs[i], bottom));
+    }
+    let transpose = rng.chance(0.5);
+    lines(corners.into_iter().map(|(x, y)| {
+        let (x, y) = if transpose { (y, x) } else { (x, y) };
+        format!("{},{}", x, y)
+    }))
+}
+
+// `size` machines. The lights and joltages come from pressing the buttons, so
+// every machine has a solution. Up to seven buttons more than lights leaves
+// that many press counts free after elimination; half of the machines press
+// each button at most three times, which keeps brute force within reach.
+fn day10(rng: &mut Rng, size: usize) -> String {
+    lines((0..size).map(|_| {
+        let n = rng.range(3, 10) as usize;
+        // There are only 2^n - 1 distinct buttons, and the parser takes 16.
+        let count = (rng.range(2, n as u64 + 7) as usize).min((1 << n) - 1).min(16);
+        let most = if rng.chance(0.5) { 3 } else { 20 };
+        let mut buttons: Vec<Vec<usize>> = Vec::new();
+        while buttons.len() < count {
+            let mut button: Vec<usize> = (0..n).filter(|_| rng.chance(0.4)).collect();
+            if button.is_empty() {
+                button.push(rng.index(n));
+            }
+            if !buttons.contains(&button) {
+                buttons.push(button);
+            }
+        }
+        // Every light gets at least one button.
+        for light in 0..n {
+            if !buttons.iter().any(|button| button.contains(&light)) {
+                let i = rng.index(count);
+                buttons[i].push(light);
+                buttons[i].sort();
+            }
+        }
+
+        let mut lights = vec![false; n];
+        let mut joltage = vec![0; n];
+        for button in &buttons {
+            let toggled = rng.chance(0.5);
+            let presses = rng.range(0, most);
+            for &light in button {
+                lights[light] ^= toggled;
+                joltage[light] += presses;
+            }
+        }
+
+        let lights: String = lights.iter().map(|&on| if on { '#' } else { '.' }).collect();
+        let buttons: Vec<String> = buttons
+            .iter()
+            .map(|button| {
+                let indices: Vec<String> = button.iter().map(usize::to_string).collect();
+                format!("({})", indices.join(","))
+            })
+            .collect();
+        let joltage: Vec<String> = joltage.iter().map(u64::to_string).collect();
+        format!("[{}] {} {{{}}}", lights, buttons.join(" "), joltage.join(","))
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+
+    #[test]
+    fn test_reproducible() {
+        for generator in GENERATORS {
+            let a = generate(generator.day, 3, Some(12)).unwrap();
+            assert_eq!(a, generate(generator.day, 3, Some(12)).unwrap());
+            assert_ne!(a, generate(generator.day, 4, Some(12)).unwrap(), "day {}", generator.day);
+        }
+        assert!(generate(11, 0, None).is_err());
+        assert!(generate(1, 0, Some(0)).is_err());
+    }
+
+    #[test]
+    fn test_every_day_solves() {
+        for day in days::DAYS {
+            // Day 8 needs enough boxes for three circuits after 1000 joins.
+            let size = if day.number == 8 { 1000 } else { 12 };
+            for seed in 0..3 {
+                // Not every day has a generator yet.
+                let Ok(input) = generate(day.number, seed, Some(size)) else { continue };
+                let parsed = day
+                    .parse(&input)
+                    .unwrap_or_else(|e| panic!("day {} seed {}: {}\n{}", day.number, seed, e, input));
+                for part in [1, 2] {
+                    if let Err(e) = day.solve(parsed.as_ref(), part) {
+                        panic!("day {} seed {} part {}: {}\n{}", day.number, seed, part, e, input);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_day09_turns_at_every_corner() {
+        let input = generate(9, 1, Some(6)).unwrap();
+        let corners: Vec<(u64, u64)> = input
+            .lines()
+            .map(|line| {
+                let (x, y) = line.split_once(',').unwrap();
+                (x.parse().unwrap(), y.parse().unwrap())
+            })
+            .collect();
+        assert_eq!(corners.len(), 24);
+        for i in 0..corners.len() {
+            let (a, b, c) = (corners[i], corners[(i + 1) % 24], corners[(i + 2) % 24]);
+            // Exactly one coordinate changes per side, and it alternates.
+            assert!((a.0 == b.0) != (a.1 == b.1));
+            assert_ne!(a.0 == b.0, b.0 == c.0);
+        }
+    }
+
+    #[test]
+    fn test_day10_is_solvable() {
+        let day = days::find(10).unwrap();
+        for seed in 0..50 {
+            let input = generate(10, seed, Some(1)).unwrap();
+            let parsed = day.parse(&input).unwrap();
+            assert!(day.part1(parsed.as_ref()).is_ok(), "{}", input);
+            assert!(day.part2(parsed.as_ref()).is_ok(), "{}", input);
+        }
+    }
+}
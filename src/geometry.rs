@@ -0,0 +1,293 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Point2 {
+    pub const fn new(x: i64, y: i64) -> Self {
+        Point2 { x, y }
+    }
+
+    pub fn manhattan(self, other: Point2) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    pub fn distance_squared(self, other: Point2) -> i64 {
+        let d = self - other;
+        d.x * d.x + d.y * d.y
+    }
+
+    // z component of the cross product of (a - self) and (b - self):
+    // positive if self, a, b turn counter-clockwise, zero if collinear.
+    pub fn cross(self, a: Point2, b: Point2) -> i64 {
+        let (u, v) = (a - self, b - self);
+        u.x * v.y - u.y * v.x
+    }
+}
+
+impl Point3 {
+    pub const fn new(x: i64, y: i64, z: i64) -> Self {
+        Point3 { x, y, z }
+    }
+
+    pub fn manhattan(self, other: Point3) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    pub fn distance_squared(self, other: Point3) -> i64 {
+        let d = self - other;
+        d.x * d.x + d.y * d.y + d.z * d.z
+    }
+}
+
+// Component-wise arithmetic, plus scaling by an i64.
+macro_rules! point_ops {
+    ($point:ident { $($field:ident),+ }) => {
+        impl Add for $point {
+            type Output = $point;
+
+            fn add(self, other: $point) -> $point {
+                $point { $($field: self.$field + other.$field),+ }
+            }
+        }
+
+        impl Sub for $point {
+            type Output = $point;
+
+            fn sub(self, other: $point) -> $point {
+                $point { $($field: self.$field - other.$field),+ }
+            }
+        }
+
+        impl Mul<i64> for $point {
+            type Output = $point;
+
+            fn mul(self, k: i64) -> $point {
+                $point { $($field: self.$field * k),+ }
+            }
+        }
+
+        impl Neg for $point {
+            type Output = $point;
+
+            fn neg(self) -> $point {
+                $point { $($field: -self.$field),+ }
+            }
+        }
+
+        impl AddAssign for $point {
+            fn add_assign(&mut self, other: $point) {
+                *self = *self + other;
+            }
+        }
+
+        impl SubAssign for $point {
+            fn sub_assign(&mut self, other: $point) {
+                *self = *self - other;
+            }
+        }
+    };
+}
+
+point_ops!(Point2 { x, y });
+point_ops!(Point3 { x, y, z });
+
+// Written the way puzzle inputs list them, "x,y" and "x,y,z".
+impl fmt::Display for Point2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+impl fmt::Display for Point3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+// A closed line segment between two points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Segment {
+    pub a: Point2,
+    pub b: Point2,
+}
+
+impl Segment {
+    pub const fn new(a: Point2, b: Point2) -> Self {
+        Segment { a, b }
+    }
+
+    pub fn is_horizontal(&self) -> bool {
+        self.a.y == self.b.y
+    }
+
+    pub fn is_vertical(&self) -> bool {
+        self.a.x == self.b.x
+    }
+
+    // Smallest rectangle covering the segment.
+    pub fn bounds(&self) -> Rect {
+        Rect::from_corners(self.a, self.b)
+    }
+
+    // True if p lies on the segment, endpoints included.
+    pub fn contains(&self, p: Point2) -> bool {
+        self.a.cross(self.b, p) == 0 && self.bounds().contains(p)
+    }
+
+    // True if the segments cross at a single point inside both of them.
+    // Touching at an endpoint, or overlapping along a line, does not count.
+    pub fn crosses(&self, other: &Segment) -> bool {
+        let side = |s: &Segment, p: Point2| s.a.cross(s.b, p).signum();
+        side(self, other.a) * side(self, other.b) < 0 && side(other, self.a) * side(other, self.b) < 0
+    }
+
+    // True if the segments share at least one point.
+    pub fn intersects(&self, other: &Segment) -> bool {
+        self.crosses(other)
+            || self.contains(other.a)
+            || self.contains(other.b)
+            || other.contains(self.a)
+            || other.contains(self.b)
+    }
+}
+
+// An axis-aligned rectangle on the integer grid, inclusive of both corners
+// the way tile puzzles count: (0,0)-(2,1) is 3 wide, 2 high, 6 tiles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rect {
+    pub min: Point2,
+    pub max: Point2,
+}
+
+impl Rect {
+    // The rectangle with a and b as opposite corners, in any order.
+    pub fn from_corners(a: Point2, b: Point2) -> Self {
+        Rect {
+            min: Point2::new(a.x.min(b.x), a.y.min(b.y)),
+            max: Point2::new(a.x.max(b.x), a.y.max(b.y)),
+        }
+    }
+
+    pub fn width(&self) -> i64 {
+        self.max.x - self.min.x + 1
+    }
+
+    pub fn height(&self) -> i64 {
+        self.max.y - self.min.y + 1
+    }
+
+    pub fn area(&self) -> i64 {
+        self.width() * self.height()
+    }
+
+    // Clockwise from min, in y-up terms.
+    pub fn corners(&self) -> [Point2; 4] {
+        [
+            self.min,
+            Point2::new(self.min.x, self.max.y),
+            self.max,
+            Point2::new(self.max.x, self.min.y),
+        ]
+    }
+
+    // The sides, each running from one corner to the next.
+    pub fn edges(&self) -> [Segment; 4] {
+        let c = self.corners();
+        [0, 1, 2, 3].map(|i| Segment::new(c[i], c[(i + 1) % 4]))
+    }
+
+    pub fn contains(&self, p: Point2) -> bool {
+        (self.min.x..=self.max.x).contains(&p.x) && (self.min.y..=self.max.y).contains(&p.y)
+    }
+
+    pub fn contains_rect(&self, other: &Rect) -> bool {
+        self.contains(other.min) && self.contains(other.max)
+    }
+
+    // The shared area, or None if the rectangles don't overlap.
+    pub fn intersection(&self, other: &Rect) -> Option<Rect> {
+        let min = Point2::new(self.min.x.max(other.min.x), self.min.y.max(other.min.y));
+        let max = Point2::new(self.max.x.min(other.max.x), self.max.y.min(other.max.y));
+        (min.x <= max.x && min.y <= max.y).then_some(Rect { min, max })
+    }
+
+    pub fn intersects(&self, other: &Rect) -> bool {
+        self.intersection(other).is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn p(x: i64, y: i64) -> Point2 {
+        Point2::new(x, y)
+    }
+
+    #[test]
+    fn test_point_ops() {
+        assert_eq!(p(1, 2) + p(3, -4), p(4, -2));
+        assert_eq!(p(1, 2) - p(3, -4), p(-2, 6));
+        assert_eq!(-p(1, 2) * 3, p(-3, -6));
+        let mut q = Point3::new(1, 2, 3);
+        q += Point3::new(1, 1, 1);
+        q -= Point3::new(0, 0, 4);
+        assert_eq!(q, Point3::new(2, 3, 0));
+        assert_eq!(q.to_string(), "2,3,0");
+    }
+
+    #[test]
+    fn test_distances() {
+        assert_eq!(p(1, 1).manhattan(p(4, -3)), 7);
+        assert_eq!(p(1, 1).distance_squared(p(4, -3)), 25);
+        let (a, b) = (Point3::new(162, 817, 812), Point3::new(425, 690, 689));
+        assert_eq!(a.distance_squared(b), 263 * 263 + 127 * 127 + 123 * 123);
+        assert_eq!(a.manhattan(b), 263 + 127 + 123);
+    }
+
+    #[test]
+    fn test_segment() {
+        let horizontal = Segment::new(p(0, 2), p(4, 2));
+        assert!(horizontal.is_horizontal() && !horizontal.is_vertical());
+        assert!(horizontal.contains(p(0, 2)) && horizontal.contains(p(3, 2)));
+        assert!(!horizontal.contains(p(5, 2)) && !horizontal.contains(p(2, 3)));
+        assert!(Segment::new(p(0, 0), p(4, 4)).contains(p(2, 2)));
+
+        // Crossing strictly inside both
+        assert!(horizontal.crosses(&Segment::new(p(2, 0), p(2, 4))));
+        // Touching at an endpoint, a T-junction, and overlapping
+        assert!(!horizontal.crosses(&Segment::new(p(4, 2), p(4, 5))));
+        assert!(!horizontal.crosses(&Segment::new(p(2, 2), p(2, 5))));
+        assert!(!horizontal.crosses(&Segment::new(p(2, 2), p(6, 2))));
+        assert!(horizontal.intersects(&Segment::new(p(2, 2), p(2, 5))));
+        assert!(horizontal.intersects(&Segment::new(p(2, 2), p(6, 2))));
+        assert!(!horizontal.intersects(&Segment::new(p(5, 0), p(5, 4))));
+    }
+
+    #[test]
+    fn test_rect() {
+        let rect = Rect::from_corners(p(11, 1), p(7, 3));
+        assert_eq!((rect.min, rect.max), (p(7, 1), p(11, 3)));
+        assert_eq!((rect.width(), rect.height(), rect.area()), (5, 3, 15));
+        assert!(rect.contains(p(7, 3)) && !rect.contains(p(6, 3)));
+        assert_eq!(rect.edges()[0], Segment::new(p(7, 1), p(7, 3)));
+
+        let other = Rect::from_corners(p(9, 2), p(20, 20));
+        assert_eq!(rect.intersection(&other), Some(Rect::from_corners(p(9, 2), p(11, 3))));
+        assert!(!rect.intersects(&Rect::from_corners(p(12, 0), p(13, 5))));
+        assert!(rect.contains_rect(&Rect::from_corners(p(8, 2), p(9, 3))));
+        assert!(!rect.contains_rect(&other));
+    }
+}
@@ -0,0 +1,231 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::parse::{self, ParseError};
+
+// A cell position, row 0 being the first line of the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Point {
+    pub const fn new(row: usize, col: usize) -> Self {
+        Point { row, col }
+    }
+
+    // The point moved by (drow, dcol), or None if that leaves the first
+    // quadrant.
+    pub fn offset(self, drow: isize, dcol: isize) -> Option<Point> {
+        Some(Point::new(self.row.checked_add_signed(drow)?, self.col.checked_add_signed(dcol)?))
+    }
+}
+
+const DIRECTIONS4: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+const DIRECTIONS8: [(isize, isize); 8] = [(-1, -1), (-1, 0), (-1, 1), (0, -1), (0, 1), (1, -1), (1, 0), (1, 1)];
+
+// Rectangular 2D grid stored row-major in one Vec.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    // None if the rows differ in length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |r| r.len());
+        if rows.iter().any(|r| r.len() != width) {
+            return None;
+        }
+        Some(Grid {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        p.row < self.height && p.col < self.width
+    }
+
+    pub fn get(&self, p: Point) -> Option<&T> {
+        if self.contains(p) {
+            Some(&self.cells[p.row * self.width + p.col])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        if self.contains(p) {
+            Some(&mut self.cells[p.row * self.width + p.col])
+        } else {
+            None
+        }
+    }
+
+    // Every position, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> + use<T> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| Point::new(row, col)))
+    }
+
+    // Orthogonal neighbours inside the grid.
+    pub fn neighbours4(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        self.neighbours(p, &DIRECTIONS4)
+    }
+
+    // Orthogonal and diagonal neighbours inside the grid.
+    pub fn neighbours8(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        self.neighbours(p, &DIRECTIONS8)
+    }
+
+    fn neighbours(&self, p: Point, directions: &'static [(isize, isize)]) -> impl Iterator<Item = Point> + '_ {
+        directions
+            .iter()
+            .filter_map(move |&(dr, dc)| p.offset(dr, dc))
+            .filter(|&n| self.contains(n))
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks() panics on 0, and an empty grid has no rows anyway.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.width, "column {} out of bounds", col);
+        self.cells.iter().skip(col).step_by(self.width)
+    }
+
+    // First position holding `value`, row by row.
+    pub fn find(&self, value: &T) -> Option<Point>
+    where
+        T: PartialEq,
+    {
+        self.points().find(|&p| self[p] == *value)
+    }
+
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Point> + 'a
+    where
+        T: PartialEq,
+    {
+        self.points().filter(move |&p| self[p] == *value)
+    }
+}
+
+impl Grid<char> {
+    // One row per line; see parse::grid for what is accepted.
+    pub fn parse(input: &str, allowed: &str) -> Result<Self, ParseError> {
+        let rows = parse::grid(input, allowed)?;
+        Ok(Grid::from_rows(rows).expect("parse::grid checks rows are the same width"))
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &T {
+        self.get(p).unwrap_or_else(|| panic!("{:?} out of bounds", p))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut T {
+        self.get_mut(p).unwrap_or_else(|| panic!("{:?} out of bounds", p))
+    }
+}
+
+// Rows on separate lines, cells written back to back.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GRID: &str = "..@\n@S.\n";
+
+    #[test]
+    fn test_parse_and_display() {
+        let grid = Grid::parse(GRID, ".@S").unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point::new(1, 1)], 'S');
+        assert_eq!(grid.to_string(), GRID);
+        assert!(Grid::parse("..\n.\n", ".").is_err());
+    }
+
+    #[test]
+    fn test_get() {
+        let mut grid = Grid::parse(GRID, ".@S").unwrap();
+        assert_eq!(grid.get(Point::new(0, 2)), Some(&'@'));
+        assert_eq!(grid.get(Point::new(0, 3)), None);
+        assert_eq!(grid.get(Point::new(2, 0)), None);
+        *grid.get_mut(Point::new(0, 0)).unwrap() = '@';
+        grid[Point::new(0, 1)] = '@';
+        assert_eq!(grid.row(0), ['@', '@', '@']);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::new(3, 3, 0);
+        let corner: Vec<Point> = grid.neighbours4(Point::new(0, 0)).collect();
+        assert_eq!(corner, [Point::new(0, 1), Point::new(1, 0)]);
+        assert_eq!(grid.neighbours8(Point::new(0, 0)).count(), 3);
+        assert_eq!(grid.neighbours8(Point::new(1, 1)).count(), 8);
+        assert_eq!(grid.neighbours4(Point::new(2, 1)).count(), 3);
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+        let rows: Vec<&[i32]> = grid.rows().collect();
+        assert_eq!(rows, [&[1, 2, 3][..], &[4, 5, 6][..]]);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), [2, 5]);
+        assert_eq!(Grid::from_rows(vec![vec![1], vec![2, 3]]), None);
+    }
+
+    #[test]
+    fn test_find() {
+        let grid = Grid::parse("@.@\n.@.\n", ".@").unwrap();
+        assert_eq!(grid.find(&'@'), Some(Point::new(0, 0)));
+        assert_eq!(grid.find(&'S'), None);
+        assert_eq!(grid.find_all(&'@').count(), 3);
+        assert_eq!(grid.points().count(), 6);
+    }
+}
@@ -0,0 +1,381 @@
+use std::fmt;
+
+use crate::transcript::{Timestamp, ToolCall, Transcript};
+
+// Rebuilds the versions a file went through during a session by replaying
+// the Write and Edit calls in a transcript. The client also logs each file as
+// it was before the call, which catches changes made some other way (cargo
+// fmt, the user's editor) and gives a starting point for files that already
+// existed.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Change {
+    // The file as first seen, before the session touched it.
+    Original,
+    Write,
+    Edit,
+    // The file differed from the replayed version when next seen.
+    External,
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(match self {
+            Change::Original => "original",
+            Change::Write => "write",
+            Change::Edit => "edit",
+            Change::External => "external",
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Version {
+    // 1-based.
+    pub number: usize,
+    pub timestamp: Timestamp,
+    pub change: Change,
+    pub content: String,
+}
+
+// A file-history-snapshot checkpoint: the client's backup number `version`,
+// taken at `time`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Checkpoint {
+    pub version: u32,
+    pub time: Timestamp,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct History {
+    // Relative to the session's working directory.
+    pub path: String,
+    pub versions: Vec<Version>,
+    pub checkpoints: Vec<Checkpoint>,
+    // Edits that could not be replayed.
+    pub problems: Vec<String>,
+}
+
+impl History {
+    pub fn replay(transcript: &Transcript, path: &str) -> History {
+        let mut history = History {
+            path: path.to_string(),
+            ..History::default()
+        };
+        for call in transcript.tool_calls() {
+            if relative_path(&call).as_deref() == Some(path) {
+                history.apply(&call);
+            }
+        }
+        for snapshot in transcript.snapshots() {
+            for file in snapshot.files.iter().filter(|f| f.path == path) {
+                let time = file.backup_time.unwrap_or(snapshot.timestamp);
+                if history.checkpoints.iter().all(|c| c.version != file.version) {
+                    history.checkpoints.push(Checkpoint {
+                        version: file.version,
+                        time,
+                    });
+                }
+            }
+        }
+        history
+    }
+
+    pub fn latest(&self) -> Option<&Version> {
+        self.versions.last()
+    }
+
+    pub fn get(&self, number: usize) -> Option<&Version> {
+        self.versions.get(number.checked_sub(1)?)
+    }
+
+    // The version current at `time`.
+    pub fn at(&self, time: Timestamp) -> Option<&Version> {
+        self.versions.iter().rev().find(|v| v.timestamp <= time)
+    }
+
+    fn push(&mut self, timestamp: Timestamp, change: Change, content: String) {
+        self.versions.push(Version {
+            number: self.versions.len() + 1,
+            timestamp,
+            change,
+            content,
+        });
+    }
+
+    // Records `seen` as the file's content, if that is news.
+    fn observe(&mut self, timestamp: Timestamp, seen: &str) {
+        match self.latest() {
+            Some(latest) if latest.content == seen => {}
+            Some(_) => self.push(timestamp, Change::External, seen.to_string()),
+            None => self.push(timestamp, Change::Original, seen.to_string()),
+        }
+    }
+
+    fn apply(&mut self, call: &ToolCall) {
+        let Some(result) = call.result.filter(|r| !r.is_error) else {
+            return;
+        };
+        let logged = result.message.tool_use_result.as_ref();
+        let timestamp = call.message.timestamp;
+        let input = |key: &str| call.input.get(key).as_str();
+
+        match call.name {
+            "Read" => {
+                // Only whole-file reads say what the file is.
+                let Some(file) = logged.map(|v| v.get("file")) else { return };
+                let whole = file.get("startLine").as_u64() == Some(1)
+                    && file.get("numLines").as_u64() == file.get("totalLines").as_u64();
+                if let Some(content) = file.get("content").as_str().filter(|_| whole) {
+                    self.observe(timestamp, content);
+                }
+            }
+            "Write" => {
+                if let Some(before) = logged.and_then(|v| v.get("originalFile").as_str()) {
+                    self.observe(timestamp, before);
+                }
+                self.push(timestamp, Change::Write, input("content").unwrap_or("").to_string());
+            }
+            "Edit" => {
+                if let Some(before) = logged.and_then(|v| v.get("originalFile").as_str()) {
+                    self.observe(timestamp, before);
+                }
+                let (Some(old), Some(new)) = (input("old_string"), input("new_string")) else {
+                    return;
+                };
+                let Some(current) = self.latest().map(|v| v.content.clone()) else {
+                    self.problems.push(format!("{}: edit of a file never seen", call.id));
+                    return;
+                };
+                if !current.contains(old) {
+                    self.problems.push(format!("{}: text to replace not found", call.id));
+                    return;
+                }
+                let edited = if call.input.get("replace_all").as_bool() == Some(true) {
+                    current.replace(old, new)
+                } else {
+                    current.replacen(old, new, 1)
+                };
+                self.push(timestamp, Change::Edit, edited);
+            }
+            _ => {}
+        }
+    }
+}
+
+// The file a Read, Write or Edit call touched, relative to the session's
+// working directory.
+pub fn relative_path(call: &ToolCall) -> Option<String> {
+    let path = call.input.get("file_path").as_str()?;
+    let relative = call
+        .message
+        .cwd
+        .as_deref()
+        .and_then(|cwd| path.strip_prefix(cwd))
+        .and_then(|rest| rest.strip_prefix('/'))
+        .unwrap_or(path);
+    Some(relative.to_string())
+}
+
+// Every file written or edited in the session, in order of first change.
+pub fn changed_files(transcript: &Transcript) -> Vec<String> {
+    let mut files: Vec<String> = Vec::new();
+    for call in transcript.tool_calls().filter(|c| c.name == "Write" || c.name == "Edit") {
+        if let Some(path) = relative_path(&call).filter(|p| !files.contains(p)) {
+            files.push(path);
+        }
+    }
+    files
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Line {
+    Same(usize, usize),
+    Removed(usize),
+    Added(usize),
+}
+
+// A unified diff with three lines of context; empty if the texts are equal.
+pub fn diff(old: &str, new: &str, old_name: &str, new_name: &str) -> String {
+    let a: Vec<&str> = old.lines().collect();
+    let b: Vec<&str> = new.lines().collect();
+    let lines = diff_lines(&a, &b);
+    if lines.iter().all(|line| matches!(line, Line::Same(..))) {
+        return String::new();
+    }
+
+    const CONTEXT: usize = 3;
+    let changed: Vec<usize> = (0..lines.len()).filter(|&i| !matches!(lines[i], Line::Same(..))).collect();
+    let mut out = format!("--- {}\n+++ {}\n", old_name, new_name);
+    let mut i = 0;
+    while i < changed.len() {
+        // Changes whose contexts touch share a hunk.
+        let mut j = i;
+        while j + 1 < changed.len() && changed[j + 1] - changed[j] <= 2 * CONTEXT + 1 {
+            j += 1;
+        }
+        let start = changed[i].saturating_sub(CONTEXT);
+        let end = (changed[j] + CONTEXT + 1).min(lines.len());
+        let hunk = &lines[start..end];
+
+        // Where the hunk starts in each file, 1-based.
+        let (mut old_start, mut new_start) = (0, 0);
+        for line in &lines[..start] {
+            match line {
+                Line::Same(..) => (old_start, new_start) = (old_start + 1, new_start + 1),
+                Line::Removed(_) => old_start += 1,
+                Line::Added(_) => new_start += 1,
+            }
+        }
+        let old_count = hunk.iter().filter(|l| !matches!(l, Line::Added(_))).count();
+        let new_count = hunk.iter().filter(|l| !matches!(l, Line::Removed(_))).count();
+        out += &format!(
+            "@@ -{},{} +{},{} @@\n",
+            old_start + usize::from(old_count > 0),
+            old_count,
+            new_start + usize::from(new_count > 0),
+            new_count
+        );
+        for line in hunk {
+            out += &match *line {
+                Line::Same(x, _) => format!(" {}\n", a[x]),
+                Line::Removed(x) => format!("-{}\n", a[x]),
+                Line::Added(y) => format!("+{}\n", b[y]),
+            };
+        }
+        i = j + 1;
+    }
+    out
+}
+
+// Lines added and removed going from `old` to `new`.
+pub fn line_changes(old: &str, new: &str) -> (usize, usize) {
+    let a: Vec<&str> = old.lines().collect();
+    let b: Vec<&str> = new.lines().collect();
+    let lines = diff_lines(&a, &b);
+    let added = lines.iter().filter(|l| matches!(l, Line::Added(_))).count();
+    let removed = lines.iter().filter(|l| matches!(l, Line::Removed(_))).count();
+    (added, removed)
+}
+
+// Longest common subsequence; solution files are small enough for the
+// quadratic table.
+fn diff_lines(a: &[&str], b: &[&str]) -> Vec<Line> {
+    let mut lcs = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    for x in (0..a.len()).rev() {
+        for y in (0..b.len()).rev() {
+            lcs[x][y] = if a[x] == b[y] { lcs[x + 1][y + 1] + 1 } else { lcs[x + 1][y].max(lcs[x][y + 1]) };
+        }
+    }
+    let (mut x, mut y) = (0, 0);
+    let mut lines = Vec::new();
+    while x < a.len() || y < b.len() {
+        if x < a.len() && y < b.len() && a[x] == b[y] {
+            lines.push(Line::Same(x, y));
+            (x, y) = (x + 1, y + 1);
+        } else if y < b.len() && (x == a.len() || lcs[x][y + 1] > lcs[x + 1][y]) {
+            lines.push(Line::Added(y));
+            y += 1;
+        } else {
+            lines.push(Line::Removed(x));
+            x += 1;
+        }
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(uuid: &str, parent: &str, minute: u32, body: &str) -> String {
+        format!(
+            r#"{{"parentUuid":"{}","isSidechain":false,"cwd":"/work","sessionId":"s","uuid":"{}","timestamp":"2025-12-01T10:{:02}:00.000Z",{}}}"#,
+            parent, uuid, minute, body
+        )
+    }
+
+    fn tool_use(id: &str, name: &str, input: &str) -> String {
+        format!(
+            r#""type":"assistant","message":{{"id":"m{}","role":"assistant","content":[{{"type":"tool_use","id":"{}","name":"{}","input":{}}}]}}"#,
+            id, id, name, input
+        )
+    }
+
+    fn tool_result(id: &str, is_error: bool, logged: &str) -> String {
+        format!(
+            r#""type":"user","message":{{"role":"user","content":[{{"type":"tool_result","tool_use_id":"{}","content":"ok","is_error":{}}}]}},"toolUseResult":{}"#,
+            id, is_error, logged
+        )
+    }
+
+    fn session() -> Transcript {
+        let lines = [
+            record("u0", "", 0, r#""type":"user","message":{"role":"user","content":"go"}"#),
+            record("a1", "u0", 1, &tool_use("t1", "Write", r#"{"file_path":"/work/src/a.rs","content":"one\ntwo\n"}"#)),
+            record("u1", "a1", 2, &tool_result("t1", false, r#"{"type":"create","originalFile":null}"#)),
+            record("a2", "u1", 3, &tool_use("t2", "Edit", r#"{"file_path":"/work/src/a.rs","old_string":"two","new_string":"2"}"#)),
+            record("u2", "a2", 4, &tool_result("t2", false, r#"{"originalFile":"one\ntwo\n"}"#)),
+            record("a3", "u2", 5, &tool_use("t3", "Edit", r#"{"file_path":"/work/src/a.rs","old_string":"nope","new_string":"x"}"#)),
+            record("u3", "a3", 6, &tool_result("t3", true, r#""Error: not found""#)),
+            // Reformatted behind the session's back.
+            record("a4", "u3", 7, &tool_use("t4", "Edit", r#"{"file_path":"/work/src/a.rs","old_string":"one","new_string":"1","replace_all":true}"#)),
+            record("u4", "a4", 8, &tool_result("t4", false, r#"{"originalFile":"one\n2\none\n"}"#)),
+            record("a5", "u4", 9, &tool_use("t5", "Edit", r#"{"file_path":"/work/Cargo.toml","old_string":"a","new_string":"b"}"#)),
+            record("u5", "a5", 10, &tool_result("t5", false, r#"{}"#)),
+            r#"{"type":"file-history-snapshot","messageId":"u4","snapshot":{"messageId":"u4","trackedFileBackups":{"src/a.rs":{"backupFileName":"abc@v1","version":1,"backupTime":"2025-12-01T10:05:30.000Z"}},"timestamp":"2025-12-01T10:06:00.000Z"},"isSnapshotUpdate":false}"#.to_string(),
+        ];
+        Transcript::parse(&lines.join("\n")).unwrap()
+    }
+
+    #[test]
+    fn test_replay() {
+        let transcript = session();
+        assert_eq!(changed_files(&transcript), ["src/a.rs", "Cargo.toml"]);
+
+        let history = History::replay(&transcript, "src/a.rs");
+        let versions: Vec<(usize, Change, &str)> =
+            history.versions.iter().map(|v| (v.number, v.change, v.content.as_str())).collect();
+        assert_eq!(
+            versions,
+            [
+                (1, Change::Write, "one\ntwo\n"),
+                (2, Change::Edit, "one\n2\n"),
+                (3, Change::External, "one\n2\none\n"),
+                (4, Change::Edit, "1\n2\n1\n"),
+            ]
+        );
+        assert!(history.problems.is_empty());
+        assert_eq!(history.checkpoints.len(), 1);
+        assert_eq!(history.at(history.checkpoints[0].time).unwrap().number, 2);
+        assert_eq!(history.get(0), None);
+
+        let cargo = History::replay(&transcript, "Cargo.toml");
+        assert!(cargo.versions.is_empty());
+        assert_eq!(cargo.problems, ["t5: edit of a file never seen"]);
+    }
+
+    #[test]
+    fn test_diff() {
+        let old = "a\nb\nc\nd\ne\nf\ng\nh\ni\nj\nk\n";
+        let new = "a\nB\nc\nd\ne\nf\ng\nh\ni\nj\nk\nl\n";
+        assert_eq!(
+            diff(old, new, "v1", "v2"),
+            "--- v1\n+++ v2\n@@ -1,5 +1,5 @@\n a\n-b\n+B\n c\n d\n e\n@@ -9,3 +9,4 @@\n i\n j\n k\n+l\n"
+        );
+        assert_eq!(diff("x\ny\n", "x\ny\n", "a", "b"), "");
+        assert_eq!(diff("", "new\n", "a", "b"), "--- a\n+++ b\n@@ -0,0 +1,1 @@\n+new\n");
+        assert_eq!(diff("a\nb\nc\n", "a\nc\n", "a", "b"), "--- a\n+++ b\n@@ -1,3 +1,2 @@\n a\n-b\n c\n");
+        assert_eq!(line_changes(old, new), (2, 1));
+    }
+
+    #[test]
+    fn test_committed_transcripts() {
+        let root = std::path::Path::new(env!("CARGO_MANIFEST_DIR"));
+        for (day, transcript) in crate::transcript::load_days(&root.join(crate::transcript::DIR)).unwrap() {
+            let history = History::replay(&transcript, &format!("src/day{:02}.rs", day));
+            assert!(!history.versions.is_empty(), "day {}", day);
+            assert!(history.problems.is_empty(), "day {}: {:?}", day, history.problems);
+        }
+    }
+}
@@ -0,0 +1,410 @@
+// Converts puzzle pages from adventofcode.com into the markdown kept under
+// tasks/. Only as much HTML as those pages use is understood.
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Node {
+    Text(String),
+    Element {
+        name: String,
+        attrs: Attrs,
+        children: Vec<Node>,
+    },
+}
+
+type Attrs = Vec<(String, String)>;
+
+// An element still being parsed: name, attributes and the children so far.
+type Open = (String, Attrs, Vec<Node>);
+
+const VOID: &[&str] = &["br", "hr", "img", "input", "link", "meta", "wbr"];
+const RAW_TEXT: &[&str] = &["script", "style"];
+
+// The markdown for each <article> on the page, in order: part 1, then part 2
+// once it is unlocked.
+pub fn articles(page: &str) -> Vec<String> {
+    let nodes = parse(page);
+    let mut found = Vec::new();
+    find_elements(&nodes, "article", &mut found);
+    found.into_iter().map(|article| blocks(children(article))).collect()
+}
+
+fn find_elements<'a>(nodes: &'a [Node], tag: &str, found: &mut Vec<&'a Node>) {
+    for node in nodes {
+        if let Node::Element { name, children, .. } = node {
+            if name == tag {
+                found.push(node);
+            } else {
+                find_elements(children, tag, found);
+            }
+        }
+    }
+}
+
+fn children(node: &Node) -> &[Node] {
+    match node {
+        Node::Element { children, .. } => children,
+        Node::Text(_) => &[],
+    }
+}
+
+fn attr<'a>(node: &'a Node, key: &str) -> Option<&'a str> {
+    match node {
+        Node::Element { attrs, .. } => attrs.iter().find(|(k, _)| k == key).map(|(_, v)| v.as_str()),
+        Node::Text(_) => None,
+    }
+}
+
+// Blocks separated by blank lines, no trailing newline, like the
+// hand-converted files.
+fn blocks(nodes: &[Node]) -> String {
+    let mut out: Vec<String> = Vec::new();
+    for node in nodes {
+        let Node::Element { name, children, .. } = node else {
+            let text = inline(std::slice::from_ref(node), false);
+            if !text.trim().is_empty() {
+                out.push(escape_line_start(text.trim()));
+            }
+            continue;
+        };
+        match name.as_str() {
+            "h1" | "h2" | "h3" => {
+                let level = "#".repeat(name[1..].parse().unwrap_or(2));
+                out.push(format!("{} {}", level, escape_line_start(inline(children, false).trim())));
+            }
+            "p" => out.push(escape_line_start(inline(children, false).trim())),
+            "pre" => {
+                let mut code = decode(&text_content(children));
+                if !code.ends_with('\n') {
+                    code.push('\n');
+                }
+                out.push(format!("```\n{}```", code));
+            }
+            "ul" | "ol" => {
+                let items: Vec<String> = children
+                    .iter()
+                    .filter(|c| matches!(c, Node::Element { name, .. } if name == "li"))
+                    .enumerate()
+                    .map(|(i, li)| {
+                        let marker = if name == "ol" { format!("{}.  ", i + 1) } else { "*   ".to_string() };
+                        format!("{}{}", marker, inline(self::children(li), false).trim())
+                    })
+                    .collect();
+                out.push(items.join("\n"));
+            }
+            _ => {
+                let nested = blocks(children);
+                if !nested.is_empty() {
+                    out.push(nested);
+                }
+            }
+        }
+    }
+    out.join("\n\n")
+}
+
+// Inline markup: _emphasis_, `code`, [links](href). Inside code nothing is
+// escaped, but emphasis still shows as `_40_`, which is how answers are marked.
+fn inline(nodes: &[Node], in_code: bool) -> String {
+    let mut out = String::new();
+    for node in nodes {
+        match node {
+            Node::Text(text) => {
+                let text = decode(text);
+                if in_code {
+                    out += &text;
+                } else {
+                    out += &escape(&collapse_whitespace(&text));
+                }
+            }
+            Node::Element { name, children, .. } => match name.as_str() {
+                "em" | "i" => out += &format!("_{}_", inline(children, in_code)),
+                "strong" | "b" if !in_code => out += &format!("**{}**", inline(children, in_code)),
+                "code" if !in_code => out += &format!("`{}`", inline(children, true)),
+                "a" if !in_code => match attr(node, "href") {
+                    Some(href) => out += &format!("[{}]({})", inline(children, in_code), decode(href)),
+                    None => out += &inline(children, in_code),
+                },
+                "br" => out += "\n",
+                _ => out += &inline(children, in_code),
+            },
+        }
+    }
+    out
+}
+
+fn text_content(nodes: &[Node]) -> String {
+    nodes
+        .iter()
+        .map(|node| match node {
+            Node::Text(text) => text.clone(),
+            Node::Element { children, .. } => text_content(children),
+        })
+        .collect()
+}
+
+fn collapse_whitespace(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut space = false;
+    for c in text.chars() {
+        if c.is_whitespace() {
+            space = true;
+        } else {
+            if space {
+                out.push(' ');
+                space = false;
+            }
+            out.push(c);
+        }
+    }
+    if space {
+        out.push(' ');
+    }
+    out
+}
+
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '\\' | '*' | '_' | '`' | '[' | ']') {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out
+}
+
+// Text that markdown would read as a list, heading, quote or rule when it
+// starts a line, as in "## \--- Day 10: Factory ---".
+fn escape_line_start(text: &str) -> String {
+    let numbered = text.find(". ").is_some_and(|i| i > 0 && text[..i].bytes().all(|b| b.is_ascii_digit()));
+    if text.starts_with(['-', '+', '#', '>', '=']) {
+        format!("\\{}", text)
+    } else if numbered {
+        text.replacen(". ", "\\. ", 1)
+    } else {
+        text.to_string()
+    }
+}
+
+fn decode(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(amp) = rest.find('&') {
+        out += &rest[..amp];
+        rest = &rest[amp..];
+        let entity = rest[1..].find(';').map(|i| &rest[1..1 + i]).filter(|e| e.len() <= 10);
+        let decoded = entity.and_then(|e| match e {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some('\u{a0}'),
+            _ => e
+                .strip_prefix("#x")
+                .or_else(|| e.strip_prefix("#X"))
+                .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                .or_else(|| e.strip_prefix('#').and_then(|dec| dec.parse().ok()))
+                .and_then(char::from_u32),
+        });
+        match (entity, decoded) {
+            (Some(entity), Some(c)) => {
+                out.push(c);
+                rest = &rest[entity.len() + 2..];
+            }
+            _ => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out + rest
+}
+
+// A forgiving tree builder: unknown closing tags are ignored and anything
+// left open is closed at the end.
+fn parse(html: &str) -> Vec<Node> {
+    // The root has no name.
+    let mut stack: Vec<Open> = vec![(String::new(), Vec::new(), Vec::new())];
+    let mut rest = html;
+
+    fn close(stack: &mut Vec<Open>) {
+        let (name, attrs, children) = stack.pop().expect("never closes the root");
+        stack.last_mut().unwrap().2.push(Node::Element { name, attrs, children });
+    }
+
+    while !rest.is_empty() {
+        let Some(lt) = rest.find('<') else {
+            stack.last_mut().unwrap().2.push(Node::Text(rest.to_string()));
+            break;
+        };
+        if lt > 0 {
+            stack.last_mut().unwrap().2.push(Node::Text(rest[..lt].to_string()));
+            rest = &rest[lt..];
+        }
+
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.find("-->").map_or("", |i| &comment[i + 3..]);
+        } else if rest.starts_with("<!") || rest.starts_with("<?") {
+            rest = rest.find('>').map_or("", |i| &rest[i + 1..]);
+        } else if let Some(tag) = rest.strip_prefix("</") {
+            let end = tag.find('>').unwrap_or(tag.len());
+            let name = tag[..end].trim().to_ascii_lowercase();
+            rest = tag.get(end + 1..).unwrap_or("");
+            if let Some(depth) = stack.iter().rposition(|(open, _, _)| *open == name) {
+                while stack.len() > depth.max(1) {
+                    close(&mut stack);
+                }
+            }
+        } else if rest[1..].starts_with(|c: char| c.is_ascii_alphabetic()) {
+            let end = rest.find('>').unwrap_or(rest.len());
+            let tag = &rest[1..end];
+            rest = rest.get(end + 1..).unwrap_or("");
+            let self_closing = tag.ends_with('/');
+            let tag = tag.trim_end_matches('/');
+            let name_end = tag.find(|c: char| c.is_whitespace()).unwrap_or(tag.len());
+            let name = tag[..name_end].to_ascii_lowercase();
+            let attrs = parse_attrs(&tag[name_end..]);
+
+            if RAW_TEXT.contains(&name.as_str()) {
+                let close_tag = format!("</{}", name);
+                let end = rest.to_ascii_lowercase().find(&close_tag).unwrap_or(rest.len());
+                let text = vec![Node::Text(rest[..end].to_string())];
+                stack.last_mut().unwrap().2.push(Node::Element { name, attrs, children: text });
+                rest = &rest[end..];
+                rest = rest.find('>').map_or("", |i| &rest[i + 1..]);
+            } else if self_closing || VOID.contains(&name.as_str()) {
+                stack.last_mut().unwrap().2.push(Node::Element {
+                    name,
+                    attrs,
+                    children: Vec::new(),
+                });
+            } else {
+                stack.push((name, attrs, Vec::new()));
+            }
+        } else {
+            // A stray '<' in text.
+            stack.last_mut().unwrap().2.push(Node::Text("<".to_string()));
+            rest = &rest[1..];
+        }
+    }
+
+    while stack.len() > 1 {
+        close(&mut stack);
+    }
+    stack.pop().unwrap().2
+}
+
+fn parse_attrs(mut text: &str) -> Attrs {
+    let mut attrs = Vec::new();
+    loop {
+        text = text.trim_start();
+        if text.is_empty() {
+            return attrs;
+        }
+        let name_end = text.find(|c: char| c.is_whitespace() || c == '=').unwrap_or(text.len());
+        let name = text[..name_end].to_ascii_lowercase();
+        text = text[name_end..].trim_start();
+
+        let value = if let Some(after) = text.strip_prefix('=') {
+            let after = after.trim_start();
+            let (value, rest) = match after.chars().next() {
+                Some(quote @ ('"' | '\'')) => {
+                    let close = after[1..].find(quote).map_or(after.len(), |i| i + 1);
+                    (&after[1..close], after.get(close + 1..).unwrap_or(""))
+                }
+                _ => {
+                    let end = after.find(char::is_whitespace).unwrap_or(after.len());
+                    (&after[..end], &after[end..])
+                }
+            };
+            text = rest;
+            decode(value)
+        } else {
+            String::new()
+        };
+        attrs.push((name, value));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Trimmed down from a real puzzle page.
+    const PAGE: &str = r#"<!DOCTYPE html>
+<html lang="en-us">
+<head><title>Day 10 - Advent of Code 2025</title><script>if (a < b) { x(); }</script></head>
+<body>
+<main>
+<article class="day-desc"><h2>--- Day 10: Factory ---</h2><p>Just across the hall, you find a <em>large</em> factory, eaten by a <a href="https://en.wikipedia.org/wiki/Shiba_Inu" target="_blank">Shiba Inu</a>.</p>
+<p>For example:</p>
+<pre><code>[.##.] (3) (1,3) {3,5,4,7}
+a &lt; b &amp;&amp; c
+</code></pre>
+<p>Lights in <code>[</code>square brackets<code>]</code>, a_b * 2.</p>
+<ul>
+<li>You could press <code>(1,3)</code> once.</li>
+<li>Or <span title="easter egg">twice</span>.</li>
+</ul>
+<p>The fewest presses is <code><em>2</em></code>, or <em><code>7</code></em> in total.</p>
+</article>
+<p>Your puzzle answer was <code>7</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p><em>What is the fewest?</em></p>
+</article>
+</main>
+</body>
+</html>"#;
+
+    #[test]
+    fn test_articles() {
+        let articles = articles(PAGE);
+        assert_eq!(articles.len(), 2);
+        assert_eq!(
+            articles[0],
+            "## \\--- Day 10: Factory ---
+
+Just across the hall, you find a _large_ factory, eaten by a [Shiba Inu](https://en.wikipedia.org/wiki/Shiba_Inu).
+
+For example:
+
+```
+[.##.] (3) (1,3) {3,5,4,7}
+a < b && c
+```
+
+Lights in `[`square brackets`]`, a\\_b \\* 2.
+
+*   You could press `(1,3)` once.
+*   Or twice.
+
+The fewest presses is `_2_`, or _`7`_ in total."
+        );
+        assert_eq!(articles[1], "## \\--- Part Two ---\n\n_What is the fewest?_");
+    }
+
+    #[test]
+    fn test_articles_feed_the_task_helpers() {
+        let part1 = &articles(PAGE)[0];
+        assert_eq!(crate::task::example(part1).as_deref(), Some("[.##.] (3) (1,3) {3,5,4,7}\na < b && c\n"));
+        assert_eq!(crate::task::answer(part1).as_deref(), Some("7"));
+    }
+
+    #[test]
+    fn test_decode() {
+        assert_eq!(decode("&lt;a&gt; &amp; &#39;b&#x27; &bogus; & done"), "<a> & 'b' &bogus; & done");
+    }
+
+    #[test]
+    fn test_escape_line_start() {
+        assert_eq!(escape_line_start("--- Part Two ---"), "\\--- Part Two ---");
+        assert_eq!(escape_line_start("1. first"), "1\\. first");
+        assert_eq!(escape_line_start("plain - text"), "plain - text");
+    }
+
+    #[test]
+    fn test_unbalanced_markup() {
+        assert_eq!(articles("<article><p>a <em>b</p></div>"), ["a _b_"]);
+        assert!(articles("no articles < here").is_empty());
+    }
+}
@@ -0,0 +1,223 @@
+use std::io::{ErrorKind, Read, Write};
+use std::net::TcpStream;
+use std::process::{Command, Stdio};
+use std::time::Duration;
+
+// Just enough HTTP for talking to adventofcode.com. Plain http:// URLs (the
+// test stub) are spoken to directly; https:// goes through curl.
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+pub fn get(url: &str, headers: &[(&str, &str)]) -> Result<Response, String> {
+    send("GET", url, headers, None)
+}
+
+// POSTs `form` as application/x-www-form-urlencoded.
+pub fn post_form(url: &str, headers: &[(&str, &str)], form: &[(&str, &str)]) -> Result<Response, String> {
+    let body: Vec<String> = form
+        .iter()
+        .map(|(name, value)| format!("{}={}", url_encode(name), url_encode(value)))
+        .collect();
+    let mut headers = headers.to_vec();
+    headers.push(("Content-Type", "application/x-www-form-urlencoded"));
+    send("POST", url, &headers, Some(&body.join("&")))
+}
+
+fn url_encode(text: &str) -> String {
+    let mut out = String::new();
+    for byte in text.bytes() {
+        if byte.is_ascii_alphanumeric() || b"-_.~".contains(&byte) {
+            out.push(byte as char);
+        } else {
+            out += &format!("%{:02X}", byte);
+        }
+    }
+    out
+}
+
+fn send(method: &str, url: &str, headers: &[(&str, &str)], body: Option<&str>) -> Result<Response, String> {
+    if let Some(rest) = url.strip_prefix("http://") {
+        send_plain(method, rest, headers, body).map_err(|e| format!("{}: {}", url, e))
+    } else if url.starts_with("https://") {
+        send_curl(method, url, headers, body)
+    } else {
+        Err(format!("{}: only http:// and https:// URLs are supported", url))
+    }
+}
+
+// `rest` is the URL after "http://". HTTP/1.0 keeps the response unchunked.
+fn send_plain(method: &str, rest: &str, headers: &[(&str, &str)], body: Option<&str>) -> Result<Response, String> {
+    let (host, path) = match rest.find('/') {
+        Some(i) => (&rest[..i], &rest[i..]),
+        None => (rest, "/"),
+    };
+    let address = if host.contains(':') { host.to_string() } else { format!("{}:80", host) };
+
+    let mut stream = TcpStream::connect(&address).map_err(|e| e.to_string())?;
+    stream.set_read_timeout(Some(TIMEOUT)).map_err(|e| e.to_string())?;
+
+    let mut request = format!("{} {} HTTP/1.0\r\nHost: {}\r\n", method, path, host);
+    for (name, value) in headers {
+        request += &format!("{}: {}\r\n", name, value);
+    }
+    if let Some(body) = body {
+        request += &format!("Content-Length: {}\r\n", body.len());
+    }
+    request += "\r\n";
+    request += body.unwrap_or("");
+    stream.write_all(request.as_bytes()).map_err(|e| e.to_string())?;
+
+    let mut raw = Vec::new();
+    stream.read_to_end(&mut raw).map_err(|e| e.to_string())?;
+    let raw = String::from_utf8_lossy(&raw);
+    let (head, body) = raw.split_once("\r\n\r\n").ok_or("malformed response")?;
+    let status = head
+        .split(' ')
+        .nth(1)
+        .and_then(|s| s.parse().ok())
+        .ok_or("malformed status line")?;
+    Ok(Response {
+        status,
+        body: body.to_string(),
+    })
+}
+
+// Headers go in on stdin so the session cookie doesn't show up in ps.
+fn send_curl(method: &str, url: &str, headers: &[(&str, &str)], body: Option<&str>) -> Result<Response, String> {
+    let mut command = Command::new("curl");
+    command
+        .args(["--silent", "--show-error", "--max-time", &TIMEOUT.as_secs().to_string()])
+        .args(["--request", method, "--header", "@-", "--write-out", "\n%{http_code}"]);
+    if let Some(body) = body {
+        command.args(["--data-binary", body]);
+    }
+    let mut child = command
+        .arg(url)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| match e.kind() {
+            ErrorKind::NotFound => "https needs curl on PATH".to_string(),
+            _ => format!("running curl: {}", e),
+        })?;
+
+    let header_lines: String = headers.iter().map(|(name, value)| format!("{}: {}\n", name, value)).collect();
+    let mut stdin = child.stdin.take().expect("stdin is piped");
+    stdin.write_all(header_lines.as_bytes()).map_err(|e| format!("running curl: {}", e))?;
+    drop(stdin);
+
+    let output = child.wait_with_output().map_err(|e| format!("running curl: {}", e))?;
+    if !output.status.success() {
+        return Err(format!("{}: {}", url, String::from_utf8_lossy(&output.stderr).trim()));
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let (body, status) = stdout.rsplit_once('\n').ok_or("curl printed no status")?;
+    Ok(Response {
+        status: status.trim().parse().map_err(|_| format!("curl printed status '{}'", status))?,
+        body: body.to_string(),
+    })
+}
+
+// A local server answering with canned responses, for testing code that
+// talks to the site.
+#[cfg(test)]
+pub mod stub {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+
+    pub struct Stub {
+        pub url: String,
+        requests: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl Stub {
+        // The raw requests received so far, head and body.
+        pub fn requests(&self) -> Vec<String> {
+            self.requests.lock().unwrap().clone()
+        }
+    }
+
+    // Serves one response per connection, in order, then stops listening.
+    pub fn serve(responses: Vec<(u16, &'static str)>) -> Stub {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let seen = Arc::clone(&requests);
+
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let Ok((stream, _)) = listener.accept() else { return };
+                let mut reader = BufReader::new(stream);
+                let mut request = String::new();
+                let mut length = 0;
+                loop {
+                    let mut line = String::new();
+                    if reader.read_line(&mut line).unwrap_or(0) == 0 || line == "\r\n" {
+                        break;
+                    }
+                    if let Some(value) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                        length = value.trim().parse().unwrap_or(0);
+                    }
+                    request += &line;
+                }
+                let mut content = vec![0; length];
+                reader.read_exact(&mut content).ok();
+                request += "\r\n";
+                request += &String::from_utf8_lossy(&content);
+                seen.lock().unwrap().push(request);
+
+                let response = format!(
+                    "HTTP/1.0 {} Stub\r\nContent-Length: {}\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                );
+                reader.get_mut().write_all(response.as_bytes()).ok();
+            }
+        });
+
+        Stub { url, requests }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get() {
+        let server = stub::serve(vec![(200, "hello\n"), (404, "missing")]);
+        let response = get(&format!("{}/a/b", server.url), &[("Cookie", "session=abc")]).unwrap();
+        assert_eq!(response, Response { status: 200, body: "hello\n".to_string() });
+        assert_eq!(get(&server.url, &[]).unwrap().status, 404);
+
+        let requests = server.requests();
+        assert!(requests[0].starts_with("GET /a/b HTTP/1.0\r\n"));
+        assert!(requests[0].contains("\r\nCookie: session=abc\r\n"));
+        assert!(requests[1].starts_with("GET / HTTP/1.0\r\n"));
+    }
+
+    #[test]
+    fn test_post_form() {
+        let server = stub::serve(vec![(200, "ok")]);
+        post_form(&format!("{}/answer", server.url), &[], &[("level", "1"), ("answer", "a b&c")]).unwrap();
+        let request = &server.requests()[0];
+        assert!(request.starts_with("POST /answer HTTP/1.0\r\n"));
+        assert!(request.contains("\r\nContent-Type: application/x-www-form-urlencoded\r\n"));
+        assert!(request.ends_with("\r\n\r\nlevel=1&answer=a%20b%26c"));
+    }
+
+    #[test]
+    fn test_unsupported_url() {
+        assert!(get("ftp://example.com", &[]).is_err());
+    }
+}
@@ -0,0 +1,66 @@
+use std::io::Read;
+
+use aoc2025::task;
+
+use crate::days::Day;
+
+// Where a day's puzzle input comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    // data/NN.txt
+    Data,
+    File(String),
+    Stdin,
+    // First example block of tasks/dayNN-1.md
+    Example,
+}
+
+impl Source {
+    // Interprets the value of `--input`, where `-` means stdin.
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            Source::Stdin
+        } else {
+            Source::File(arg.to_string())
+        }
+    }
+
+    // Data and Example resolve to a different file per day; the rest only make
+    // sense for a single day.
+    pub fn is_per_day(&self) -> bool {
+        matches!(self, Source::Data | Source::Example)
+    }
+
+    // How the source is referred to in error messages.
+    pub fn name(&self, day: &Day) -> String {
+        match self {
+            Source::Data => day.input_path(),
+            Source::File(path) => path.clone(),
+            Source::Stdin => "<stdin>".to_string(),
+            Source::Example => task::path(day.number, 1),
+        }
+    }
+
+    pub fn read(&self, day: &Day) -> Result<String, String> {
+        match self {
+            Source::Data => read_file(&day.input_path()),
+            Source::File(path) => read_file(path),
+            Source::Stdin => {
+                let mut input = String::new();
+                std::io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|e| format!("stdin: {}", e))?;
+                Ok(input)
+            }
+            Source::Example => {
+                let path = task::path(day.number, 1);
+                let markdown = read_file(&path)?;
+                task::example(&markdown).ok_or_else(|| format!("{}: no example block", path))
+            }
+        }
+    }
+}
+
+fn read_file(path: &str) -> Result<String, String> {
+    std::fs::read_to_string(path).map_err(|e| format!("{}: {}", path, e))
+}
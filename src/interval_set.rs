@@ -0,0 +1,256 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::ops::RangeInclusive;
+
+// Integers an IntervalSet can hold: each value has a neighbour on either side
+// (except at the type's limits), so touching ranges can be merged.
+pub trait Discrete: Copy + Ord {
+    fn succ(self) -> Option<Self>;
+    fn pred(self) -> Option<Self>;
+    // Number of values in lo..=hi, which must not be empty. A u128, since
+    // the full width of a 64-bit type holds one more value than it can count.
+    fn count(lo: Self, hi: Self) -> u128;
+}
+
+macro_rules! discrete {
+    ($($t:ty),+) => {
+        $(
+            impl Discrete for $t {
+                fn succ(self) -> Option<Self> {
+                    self.checked_add(1)
+                }
+
+                fn pred(self) -> Option<Self> {
+                    self.checked_sub(1)
+                }
+
+                fn count(lo: Self, hi: Self) -> u128 {
+                    hi.abs_diff(lo) as u128 + 1
+                }
+            }
+        )+
+    };
+}
+
+discrete!(u32, u64, usize, i32, i64);
+
+// A set of integers stored as disjoint inclusive ranges. Overlapping and
+// adjacent ranges are merged on insert, so iteration always yields the
+// fewest ranges covering the set, in ascending order.
+#[derive(Clone, PartialEq, Eq, Default)]
+pub struct IntervalSet<T> {
+    // start -> end, both inclusive
+    ranges: BTreeMap<T, T>,
+}
+
+// True if a range ending at `end` overlaps or runs straight into one
+// starting at `start`.
+fn touches<T: Discrete>(end: T, start: T) -> bool {
+    end >= start || end.succ() == Some(start)
+}
+
+impl<T: Discrete> IntervalSet<T> {
+    pub fn new() -> Self {
+        IntervalSet { ranges: BTreeMap::new() }
+    }
+
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (mut lo, mut hi) = range.into_inner();
+        if lo > hi {
+            return;
+        }
+        let merged: Vec<(T, T)> = self
+            .ranges
+            .range(..=hi.succ().unwrap_or(hi))
+            .rev()
+            .take_while(|&(_, &end)| touches(end, lo))
+            .map(|(&start, &end)| (start, end))
+            .collect();
+        for (start, end) in merged {
+            self.ranges.remove(&start);
+            lo = lo.min(start);
+            hi = hi.max(end);
+        }
+        self.ranges.insert(lo, hi);
+    }
+
+    pub fn remove(&mut self, range: RangeInclusive<T>) {
+        let (lo, hi) = range.into_inner();
+        if lo > hi {
+            return;
+        }
+        let overlapping: Vec<(T, T)> = self
+            .ranges
+            .range(..=hi)
+            .rev()
+            .take_while(|&(_, &end)| end >= lo)
+            .map(|(&start, &end)| (start, end))
+            .collect();
+        for (start, end) in overlapping {
+            self.ranges.remove(&start);
+            if start < lo {
+                // lo > start, so it has a predecessor
+                self.ranges.insert(start, lo.pred().unwrap());
+            }
+            if end > hi {
+                self.ranges.insert(hi.succ().unwrap(), end);
+            }
+        }
+    }
+
+    // O(log n) in the number of ranges.
+    pub fn contains(&self, value: T) -> bool {
+        self.ranges.range(..=value).next_back().is_some_and(|(_, &end)| end >= value)
+    }
+
+    pub fn union(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut result = self.clone();
+        result.extend(other.iter());
+        result
+    }
+
+    pub fn intersection(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let (a, b): (Vec<_>, Vec<_>) = (self.ranges.iter().collect(), other.ranges.iter().collect());
+        let (mut i, mut j) = (0, 0);
+        let mut result = IntervalSet::new();
+        while i < a.len() && j < b.len() {
+            let lo = *a[i].0.max(b[j].0);
+            let hi = *a[i].1.min(b[j].1);
+            result.insert(lo..=hi);
+            // Drop whichever range ends first; the other may overlap more.
+            if a[i].1 < b[j].1 {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        result
+    }
+
+    pub fn difference(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut result = self.clone();
+        for range in other.iter() {
+            result.remove(range);
+        }
+        result
+    }
+
+    // Total number of values covered.
+    pub fn len(&self) -> u128 {
+        self.ranges.iter().map(|(&start, &end)| T::count(start, end)).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    pub fn range_count(&self) -> usize {
+        self.ranges.len()
+    }
+
+    pub fn min(&self) -> Option<T> {
+        self.ranges.first_key_value().map(|(&start, _)| start)
+    }
+
+    pub fn max(&self) -> Option<T> {
+        self.ranges.last_key_value().map(|(_, &end)| end)
+    }
+
+    // The normalized ranges, ascending.
+    pub fn iter(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.ranges.iter().map(|(&start, &end)| start..=end)
+    }
+}
+
+impl<T: Discrete> Extend<RangeInclusive<T>> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = RangeInclusive<T>>>(&mut self, iter: I) {
+        for range in iter {
+            self.insert(range);
+        }
+    }
+}
+
+impl<T: Discrete> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> Self {
+        let mut set = IntervalSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<T: Discrete + fmt::Debug> fmt::Debug for IntervalSet<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[(u64, u64)]) -> IntervalSet<u64> {
+        ranges.iter().map(|&(lo, hi)| lo..=hi).collect()
+    }
+
+    fn ranges(set: &IntervalSet<u64>) -> Vec<(u64, u64)> {
+        set.iter().map(|r| r.into_inner()).collect()
+    }
+
+    #[test]
+    fn test_insert_merges() {
+        // The day 5 example: 12-18 bridges 10-14 and 16-20
+        let fresh = set(&[(3, 5), (10, 14), (16, 20), (12, 18)]);
+        assert_eq!(ranges(&fresh), [(3, 5), (10, 20)]);
+        assert_eq!(fresh.len(), 14);
+
+        // Adjacent ranges merge, a gap of one doesn't
+        assert_eq!(ranges(&set(&[(1, 2), (3, 4), (6, 7)])), [(1, 4), (6, 7)]);
+        assert_eq!(ranges(&set(&[(5, 9), (1, 20), (2, 3)])), [(1, 20)]);
+        assert_eq!(ranges(&set(&[(0, u64::MAX - 1), (u64::MAX, u64::MAX)])), [(0, u64::MAX)]);
+        assert!(set(&[(5, 4)]).is_empty());
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut s = set(&[(1, 10), (20, 30)]);
+        s.remove(4..=6);
+        s.remove(9..=22);
+        assert_eq!(ranges(&s), [(1, 3), (7, 8), (23, 30)]);
+        s.remove(0..=100);
+        assert!(s.is_empty());
+    }
+
+    #[test]
+    fn test_contains() {
+        let s = set(&[(3, 5), (10, 20)]);
+        let members: Vec<u64> = (0..22).filter(|&x| s.contains(x)).collect();
+        assert_eq!(members, [3, 4, 5, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20]);
+        assert_eq!((s.min(), s.max(), s.range_count()), (Some(3), Some(20), 2));
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = set(&[(1, 10), (20, 30)]);
+        let b = set(&[(5, 25), (28, 40)]);
+        assert_eq!(ranges(&a.union(&b)), [(1, 40)]);
+        assert_eq!(ranges(&a.intersection(&b)), [(5, 10), (20, 25), (28, 30)]);
+        assert_eq!(ranges(&a.difference(&b)), [(1, 4), (26, 27)]);
+        assert_eq!(ranges(&b.difference(&a)), [(11, 19), (31, 40)]);
+        assert!(a.intersection(&IntervalSet::new()).is_empty());
+    }
+
+    #[test]
+    fn test_signed() {
+        let s: IntervalSet<i64> = [-5..=-1, 0..=3].into_iter().collect();
+        assert_eq!(s.range_count(), 1);
+        assert_eq!(s.len(), 9);
+        assert_eq!(format!("{:?}", s), "{-5..=3}");
+    }
+
+    #[test]
+    fn test_len_full_width() {
+        assert_eq!(set(&[(0, u64::MAX)]).len(), 1 << 64);
+        let s: IntervalSet<i64> = [i64::MIN..=-1, 0..=i64::MAX].into_iter().collect();
+        assert_eq!(s.len(), 1 << 64);
+    }
+}
@@ -0,0 +1,187 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::parse::ParseError;
+
+// Known-correct answers for real inputs, stored in a small TOML file with one
+// table per day and input hash:
+//
+//     [day01.af63dc4c8601ec8c]
+//     part1 = "1052"
+//     part2 = "6295"
+pub const PATH: &str = "answers.toml";
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Entry {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Entry {
+    pub fn part(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            _ => self.part2.as_deref(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Ledger {
+    entries: BTreeMap<(u32, String), Entry>,
+}
+
+impl Ledger {
+    pub fn parse(text: &str) -> Result<Ledger, ParseError> {
+        let mut ledger = Ledger::default();
+        let mut current: Option<(u32, String)> = None;
+
+        for line in text.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(header) = line.strip_prefix('[') {
+                let header = header
+                    .strip_suffix(']')
+                    .ok_or_else(|| ParseError::at(text, &line[line.len()..], "']'"))?;
+                let (day, hash) = header
+                    .strip_prefix("day")
+                    .and_then(|h| h.split_once('.'))
+                    .ok_or_else(|| ParseError::at(text, header, "table like [day01.<input hash>]"))?;
+                let day = crate::parse::number(text, day, "day number")?;
+                let key = (day, hash.to_string());
+                ledger.entries.entry(key.clone()).or_default();
+                current = Some(key);
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| ParseError::at(text, line, "key = \"value\""))?;
+            let (key, value) = (key.trim(), value.trim());
+            let value = unquote(value).ok_or_else(|| ParseError::at(text, value, "quoted string or integer"))?;
+            let entry = current
+                .as_ref()
+                .and_then(|k| ledger.entries.get_mut(k))
+                .ok_or_else(|| ParseError::at(text, line, "a [dayNN.<hash>] table first"))?;
+            match key {
+                "part1" => entry.part1 = Some(value),
+                "part2" => entry.part2 = Some(value),
+                _ => return Err(ParseError::at(text, key, "part1 or part2")),
+            }
+        }
+
+        Ok(ledger)
+    }
+
+    // A missing file is an empty ledger.
+    pub fn load(path: &str) -> Result<Ledger, String> {
+        match std::fs::read_to_string(path) {
+            Ok(text) => Ledger::parse(&text).map_err(|e| e.in_file(path).snippet(&text)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Ledger::default()),
+            Err(e) => Err(format!("{}: {}", path, e)),
+        }
+    }
+
+    pub fn save(&self, path: &str) -> Result<(), String> {
+        std::fs::write(path, self.to_string()).map_err(|e| format!("{}: {}", path, e))
+    }
+
+    pub fn get(&self, day: u32, hash: &str) -> Option<&Entry> {
+        self.entries.get(&(day, hash.to_string()))
+    }
+
+    pub fn record(&mut self, day: u32, hash: &str, part: u8, answer: String) {
+        let entry = self.entries.entry((day, hash.to_string())).or_default();
+        match part {
+            1 => entry.part1 = Some(answer),
+            _ => entry.part2 = Some(answer),
+        }
+    }
+}
+
+impl fmt::Display for Ledger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "# Known-correct answers, keyed by day and input hash. Written by `aoc verify --record` and `aoc submit`.")?;
+        for ((day, hash), entry) in &self.entries {
+            writeln!(f, "\n[day{:02}.{}]", day, hash)?;
+            for (key, value) in [("part1", &entry.part1), ("part2", &entry.part2)] {
+                if let Some(value) = value {
+                    writeln!(f, "{} = \"{}\"", key, value.replace('\\', "\\\\").replace('"', "\\\""))?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn unquote(value: &str) -> Option<String> {
+    let Some(inner) = value.strip_prefix('"') else {
+        // Bare integers are accepted for hand-written entries.
+        return value.parse::<i128>().ok().map(|n| n.to_string());
+    };
+    let inner = inner.strip_suffix('"')?;
+
+    let mut out = String::new();
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => out.push(chars.next()?),
+            '"' => return None,
+            _ => out.push(c),
+        }
+    }
+    Some(out)
+}
+
+// FNV-1a, so hashes stay stable across Rust versions and platforms.
+pub fn input_hash(input: &str) -> String {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in input.bytes() {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    format!("{:016x}", hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_hash() {
+        assert_eq!(input_hash(""), "cbf29ce484222325");
+        assert_eq!(input_hash("a"), "af63dc4c8601ec8c");
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut ledger = Ledger::default();
+        ledger.record(10, "af63dc4c8601ec8c", 2, "33".to_string());
+        ledger.record(1, "cbf29ce484222325", 1, "3".to_string());
+        ledger.record(1, "cbf29ce484222325", 2, "say \"hi\"".to_string());
+
+        let text = ledger.to_string();
+        assert!(text.contains("\n[day01.cbf29ce484222325]\npart1 = \"3\"\npart2 = \"say \\\"hi\\\"\"\n"));
+        assert!(text.contains("\n[day10.af63dc4c8601ec8c]\npart2 = \"33\"\n"));
+        assert_eq!(Ledger::parse(&text), Ok(ledger));
+    }
+
+    #[test]
+    fn test_parse() {
+        let ledger = Ledger::parse("# answers\n[day03.abc]\npart1 = 357\npart2 = \"3121910778619\"\n").unwrap();
+        let entry = ledger.get(3, "abc").unwrap();
+        assert_eq!(entry.part(1), Some("357"));
+        assert_eq!(entry.part(2), Some("3121910778619"));
+        assert_eq!(ledger.get(3, "abd"), None);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(Ledger::parse("part1 = \"1\""), Err(ParseError::new(1, 1, "a [dayNN.<hash>] table first")));
+        assert_eq!(Ledger::parse("[day01.x]\npart3 = \"1\""), Err(ParseError::new(2, 1, "part1 or part2")));
+        assert_eq!(Ledger::parse("[days.x]"), Err(ParseError::new(1, 5, "day number")));
+    }
+}
@@ -0,0 +1,29 @@
+// Solutions index freely into grids and matrices; iterator rewrites of those
+// loops only obscure the arithmetic.
+#![allow(clippy::needless_range_loop)]
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod geometry;
+pub mod grid;
+pub mod history;
+pub mod html;
+pub mod interval_set;
+pub mod json;
+pub mod ledger;
+pub mod parse;
+pub mod random;
+pub mod search;
+pub mod solution;
+pub mod submission;
+pub mod task;
+pub mod transcript;
+pub mod union_find;
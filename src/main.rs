@@ -0,0 +1,791 @@
+mod bench;
+mod check;
+mod days;
+mod describe;
+mod examples;
+mod fuzz;
+mod generate;
+mod http;
+mod input;
+mod oracle;
+mod parallel;
+mod render;
+mod scaffold;
+mod site;
+mod stats;
+mod verify;
+
+use std::process::ExitCode;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use aoc2025::history::{self, History};
+use aoc2025::ledger::{self, Ledger};
+use aoc2025::search;
+use aoc2025::submission::{self, Outcome, Submission};
+
+use days::Day;
+use input::Source;
+
+const USAGE: &str = "usage:
+  aoc run <day|all> [--part <1|2>] [--input <path|-> | --example] [--parallel [--jobs <n>]]
+  aoc examples [day|all]
+  aoc verify [day|all] [--record]
+  aoc bench [day|all] [--runs <n>] [--warmup <n>] [--format table|json|csv] [--input <path|-> | --example]
+  aoc new <day>
+  aoc fetch <day>
+  aoc task <day> [--html <file>] [--force]
+  aoc submit <day> <1|2>
+  aoc stats transcripts [--format table|csv]
+  aoc render [--out <dir>]
+  aoc history <day> [--file <path>] [show <n> | diff <a> <b> | check [--rev <rev>]]
+  aoc search <query...> [--limit <n>]
+  aoc gen <day> [--seed <n>] [--size <n>]
+  aoc oracle [day|all] [--seeds <n>] [--size <n>]
+  aoc fuzz [day|all] [--target parse|solve] [--runs <n>] [--seed <n>] [--timeout <ms>]";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match run(&args) {
+        Ok(code) => code,
+        Err(message) => {
+            eprintln!("error: {}", message);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: &[String]) -> Result<ExitCode, String> {
+    match args.split_first() {
+        Some((command, rest)) if command == "run" => cmd_run(rest),
+        Some((command, rest)) if command == "examples" => cmd_examples(rest),
+        Some((command, rest)) if command == "verify" => cmd_verify(rest),
+        Some((command, rest)) if command == "bench" => cmd_bench(rest),
+        Some((command, rest)) if command == "new" => cmd_new(rest),
+        Some((command, rest)) if command == "fetch" => cmd_fetch(rest),
+        Some((command, rest)) if command == "task" => cmd_task(rest),
+        Some((command, rest)) if command == "submit" => cmd_submit(rest),
+        Some((command, rest)) if command == "stats" => cmd_stats(rest),
+        Some((command, rest)) if command == "render" => cmd_render(rest),
+        Some((command, rest)) if command == "history" => cmd_history(rest),
+        Some((command, rest)) if command == "search" => cmd_search(rest),
+        Some((command, rest)) if command == "gen" => cmd_gen(rest),
+        Some((command, rest)) if command == "oracle" => cmd_oracle(rest),
+        Some((command, rest)) if command == "fuzz" => cmd_fuzz(rest),
+        _ => {
+            eprintln!("{}", USAGE);
+            Ok(ExitCode::from(2))
+        }
+    }
+}
+
+fn cmd_run(args: &[String]) -> Result<ExitCode, String> {
+    let mut target = None;
+    let mut part = None;
+    let mut source = Source::Data;
+    let mut parallel = false;
+    let mut jobs = None;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--parallel" => parallel = true,
+            "--jobs" => jobs = Some(parse_count(iter.next(), "--jobs")?.max(1)),
+            "--part" => {
+                let value = iter.next().ok_or("--part needs a value")?;
+                part = match value.as_str() {
+                    "1" => Some(1),
+                    "2" => Some(2),
+                    _ => return Err(format!("invalid part '{}'", value)),
+                };
+            }
+            "--input" => {
+                let value = iter.next().ok_or("--input needs a path, or - for stdin")?;
+                set_source(&mut source, Source::from_arg(value))?;
+            }
+            "--example" => set_source(&mut source, Source::Example)?,
+            _ if target.is_none() => target = Some(arg.as_str()),
+            _ => return Err(format!("unexpected argument '{}'", arg)),
+        }
+    }
+
+    let days = select_days(target.ok_or("missing day")?)?;
+
+    if days.len() > 1 && !source.is_per_day() {
+        return Err("--input only works with a single day".to_string());
+    }
+
+    if parallel {
+        let threads = jobs.unwrap_or_else(|| thread::available_parallelism().map_or(1, usize::from));
+        return run_parallel(&days, part, &source, threads);
+    } else if jobs.is_some() {
+        return Err("--jobs only works with --parallel".to_string());
+    }
+
+    let prefix = days.len() > 1;
+    for day in days {
+        let input = source.read(day)?;
+        let label = if prefix { format!("day{:02} ", day.number) } else { String::new() };
+        let parsed = day
+            .parse(&input)
+            .map_err(|e| e.in_file(source.name(day)).snippet(&input))?;
+        for p in [1, 2] {
+            if part.is_none_or(|only| only == p) {
+                let answer = day.solve(parsed.as_ref(), p).map_err(|e| format!("day {} part {}: {}", day.number, p, e))?;
+                println!("{}part{}: {}", label, p, answer);
+            }
+        }
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+// Runs every selected part on a pool of `threads` workers and prints a table
+// of answers and timings. Failures are reported per part without stopping the
+// rest.
+fn run_parallel(days: &[&'static Day], part: Option<u8>, source: &Source, threads: usize) -> Result<ExitCode, String> {
+    let mut jobs = Vec::new();
+    for &day in days {
+        let input = source.read(day);
+        for p in [1, 2] {
+            if part.is_none_or(|only| only == p) {
+                jobs.push(parallel::Job {
+                    day,
+                    part: p,
+                    source: source.name(day),
+                    input: input.clone(),
+                });
+            }
+        }
+    }
+
+    std::panic::set_hook(Box::new(|_| {}));
+
+    let start = Instant::now();
+    let runs = parallel::run_all(&jobs, threads);
+    let wall = start.elapsed();
+
+    println!("{:>3} {:>4}  {:<24} {:>10}  status", "day", "part", "answer", "time");
+    for run in &runs {
+        let (answer, status) = match &run.answer {
+            Ok(answer) => (answer.as_str(), "ok"),
+            Err(e) => (e.as_str(), "FAILED"),
+        };
+        println!(
+            "{:>3} {:>4}  {:<24} {:>10}  {}",
+            run.day,
+            run.part,
+            answer,
+            bench::format_duration(run.elapsed),
+            status
+        );
+    }
+    let work: Duration = runs.iter().map(|run| run.elapsed).sum();
+    let failed = runs.iter().filter(|run| run.answer.is_err()).count();
+    println!(
+        "total {} with --jobs {} ({} of work), {} of {} parts failed",
+        bench::format_duration(wall),
+        threads.min(jobs.len()).max(1),
+        bench::format_duration(work),
+        failed,
+        runs.len()
+    );
+
+    Ok(if failed > 0 { ExitCode::FAILURE } else { ExitCode::SUCCESS })
+}
+
+// Checks every part against the example answer in its task file.
+fn cmd_examples(args: &[String]) -> Result<ExitCode, String> {
+    let days = match args {
+        [] => select_days("all")?,
+        [target] => select_days(target)?,
+        _ => return Err("expected at most one day".to_string()),
+    };
+
+    // Panics are reported in the table instead.
+    std::panic::set_hook(Box::new(|_| {}));
+
+    let mut failed = false;
+    println!("{:>3} {:>4}  {:<16} {:<16} status", "day", "part", "expected", "actual");
+    for day in days {
+        for check in examples::check_day(day)? {
+            failed |= !check.passed();
+            println!(
+                "{:>3} {:>4}  {:<16} {:<16} {}",
+                check.day,
+                check.part,
+                check.expected.as_deref().unwrap_or("-"),
+                check.outcome(),
+                if check.passed() { "ok" } else { "MISMATCH" }
+            );
+        }
+    }
+
+    Ok(if failed { ExitCode::FAILURE } else { ExitCode::SUCCESS })
+}
+
+// Runs days on their real inputs and compares with the answer ledger. With
+// --record, the current answers are written to the ledger instead.
+fn cmd_verify(args: &[String]) -> Result<ExitCode, String> {
+    let mut target = "all";
+    let mut record = false;
+    for arg in args {
+        match arg.as_str() {
+            "--record" => record = true,
+            _ => target = arg,
+        }
+    }
+    let days = select_days(target)?;
+    let mut ledger = Ledger::load(ledger::PATH)?;
+
+    std::panic::set_hook(Box::new(|_| {}));
+
+    let mut failed = false;
+    println!("{:>3}  {:<16}  {:<24}  {:<24}  status", "day", "input", "part1", "part2");
+    for day in days {
+        let Some(verified) = verify::verify_day(day, &ledger) else {
+            println!("{:>3}  {:<16}  {:<24}  {:<24}  no input", day.number, "-", "-", "-");
+            continue;
+        };
+
+        let status = if record {
+            for check in &verified.checks {
+                if let Ok(answer) = &check.actual {
+                    ledger.record(day.number, &verified.hash, check.part, answer.clone());
+                }
+            }
+            "recorded"
+        } else {
+            verified.status()
+        };
+        failed |= status == "MISMATCH";
+
+        println!(
+            "{:>3}  {:<16}  {:<24}  {:<24}  {}",
+            day.number,
+            verified.hash,
+            verify::cell(&verified.checks[0]),
+            verify::cell(&verified.checks[1]),
+            status
+        );
+    }
+
+    if record {
+        ledger.save(ledger::PATH)?;
+    }
+    Ok(if failed { ExitCode::FAILURE } else { ExitCode::SUCCESS })
+}
+
+fn cmd_bench(args: &[String]) -> Result<ExitCode, String> {
+    let mut target = "all";
+    let mut runs = 10;
+    let mut warmup = 2;
+    let mut format = "table";
+    let mut source = Source::Data;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--runs" => runs = parse_count(iter.next(), "--runs")?.max(1),
+            "--warmup" => warmup = parse_count(iter.next(), "--warmup")?,
+            "--format" => {
+                format = match iter.next().map(String::as_str) {
+                    Some(f @ ("table" | "json" | "csv")) => f,
+                    _ => return Err("--format needs table, json or csv".to_string()),
+                }
+            }
+            "--input" => {
+                let value = iter.next().ok_or("--input needs a path, or - for stdin")?;
+                set_source(&mut source, Source::from_arg(value))?;
+            }
+            "--example" => set_source(&mut source, Source::Example)?,
+            _ => target = arg,
+        }
+    }
+
+    let days = select_days(target)?;
+    if days.len() > 1 && !source.is_per_day() {
+        return Err("--input only works with a single day".to_string());
+    }
+
+    std::panic::set_hook(Box::new(|_| {}));
+
+    let mut timings = Vec::new();
+    for day in days {
+        let input = match source.read(day) {
+            Ok(input) => input,
+            // Benchmarking everything skips days without input.
+            Err(e) if target == "all" => {
+                eprintln!("skipping day {}: {}", day.number, e);
+                continue;
+            }
+            Err(e) => return Err(e),
+        };
+        let day_timings = match check::catch_panic(|| bench::bench_day(day, &input, runs, warmup)) {
+            Ok(result) => result.map_err(|e| e.in_file(source.name(day)).snippet(&input))?,
+            Err(e) => {
+                eprintln!("skipping day {}: {}", day.number, e);
+                continue;
+            }
+        };
+        timings.extend(day_timings);
+    }
+
+    let output = match format {
+        "json" => bench::to_json(&timings),
+        "csv" => bench::to_csv(&timings),
+        _ => bench::to_table(&timings),
+    };
+    print!("{}", output);
+    Ok(ExitCode::SUCCESS)
+}
+
+// Scaffolds a new day in the current directory, which must be the repo root.
+fn cmd_new(args: &[String]) -> Result<ExitCode, String> {
+    let [day] = args else {
+        return Err("expected a day".to_string());
+    };
+    let number: u32 = day.parse().map_err(|_| format!("invalid day '{}'", day))?;
+    for path in scaffold::new_day(std::path::Path::new("."), number)? {
+        println!("created {}", path);
+    }
+    println!("registered day {} in src/lib.rs and src/days.rs", number);
+    Ok(ExitCode::SUCCESS)
+}
+
+// Downloads a day's input into data/ unless it is already there.
+fn cmd_fetch(args: &[String]) -> Result<ExitCode, String> {
+    let [day] = args else {
+        return Err("expected a day".to_string());
+    };
+    let number: u32 = day.parse().map_err(|_| format!("invalid day '{}'", day))?;
+    match site::fetch_input(&site::Site::from_env(), number)? {
+        site::Fetched::Cached(path) => println!("{} already exists, not fetching", path.display()),
+        site::Fetched::Downloaded(path) => println!("saved {}", path.display()),
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+// Saves the puzzle description as tasks/dayNN-P.md, from the site or from a
+// page saved with --html.
+fn cmd_task(args: &[String]) -> Result<ExitCode, String> {
+    let mut day = None;
+    let mut html_file = None;
+    let mut force = false;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--html" => html_file = Some(iter.next().ok_or("--html needs a path")?),
+            "--force" => force = true,
+            _ if day.is_none() => day = Some(arg),
+            _ => return Err(format!("unexpected argument '{}'", arg)),
+        }
+    }
+    let day = day.ok_or("missing day")?;
+    let number: u32 = day.parse().map_err(|_| format!("invalid day '{}'", day))?;
+
+    let page = match html_file {
+        Some(path) => std::fs::read_to_string(path).map_err(|e| format!("{}: {}", path, e))?,
+        None => site::fetch_page(&site::Site::from_env(), number)?,
+    };
+    for (path, saved) in describe::save_tasks(std::path::Path::new(""), number, &page, force)? {
+        match saved {
+            describe::Saved::Written => println!("saved {}", path),
+            describe::Saved::Unchanged => println!("{} is up to date", path),
+            describe::Saved::Kept => println!("{} differs from the page, kept (use --force to replace)", path),
+        }
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+// Solves one part on the real input and sends the answer, unless the
+// submission log shows the site would turn it down. Accepted answers also go
+// into the answer ledger.
+fn cmd_submit(args: &[String]) -> Result<ExitCode, String> {
+    let [day, part] = args else {
+        return Err("expected a day and a part".to_string());
+    };
+    let day = parse_day(day)?;
+    let part: u8 = match part.as_str() {
+        "1" => 1,
+        "2" => 2,
+        _ => return Err(format!("invalid part '{}'", part)),
+    };
+
+    let input = Source::Data.read(day)?;
+    let parsed = day
+        .parse(&input)
+        .map_err(|e| e.in_file(day.input_path()).snippet(&input))?;
+    let answer = day
+        .solve(parsed.as_ref(), part)
+        .map_err(|e| format!("not submitting: {}", e))?
+        .to_string();
+
+    let mut log = submission::Log::load(submission::PATH)?;
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+    if let Some(reason) = log.refusal(day.number, part, &answer, now) {
+        return Err(format!("not submitting {}: {}", answer, reason));
+    }
+
+    println!("submitting day {} part {}: {}", day.number, part, answer);
+    let outcome = site::submit(&site::Site::from_env(), day.number, part, &answer)?;
+    log.append(
+        submission::PATH,
+        Submission {
+            time: now,
+            day: day.number,
+            part,
+            answer: answer.clone(),
+            outcome,
+        },
+    )?;
+    println!("{}", outcome);
+
+    if outcome != Outcome::Correct {
+        return Ok(ExitCode::FAILURE);
+    }
+    let mut ledger = Ledger::load(ledger::PATH)?;
+    ledger.record(day.number, &ledger::input_hash(&input), part, answer);
+    ledger.save(ledger::PATH)?;
+    Ok(ExitCode::SUCCESS)
+}
+
+// Per-day figures from the session logs in transcripts/.
+fn cmd_stats(args: &[String]) -> Result<ExitCode, String> {
+    let mut format = "table";
+    let mut iter = args.iter();
+    match iter.next().map(String::as_str) {
+        Some("transcripts") => {}
+        _ => return Err("expected 'transcripts'".to_string()),
+    }
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--format" => {
+                format = match iter.next().map(String::as_str) {
+                    Some(f @ ("table" | "csv")) => f,
+                    _ => return Err("--format needs table or csv".to_string()),
+                }
+            }
+            _ => return Err(format!("unexpected argument '{}'", arg)),
+        }
+    }
+
+    let days = stats::load_all(std::path::Path::new(""))?;
+    let output = match format {
+        "csv" => stats::to_csv(&days),
+        _ => stats::to_table(&days),
+    };
+    print!("{}", output);
+    Ok(ExitCode::SUCCESS)
+}
+
+// Writes HTML versions of the session logs, next to them by default.
+fn cmd_render(args: &[String]) -> Result<ExitCode, String> {
+    let out = match args {
+        [] => aoc2025::transcript::DIR,
+        [flag, dir] if flag == "--out" => dir,
+        _ => return Err("expected at most --out <dir>".to_string()),
+    };
+    for path in render::render_all(std::path::Path::new(""), std::path::Path::new(out))? {
+        println!("wrote {}", path.display());
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+// Replays a day's transcript to list the versions a file went through (by
+// default the day's solution), show or diff them, or check the last one
+// against git.
+fn cmd_history(args: &[String]) -> Result<ExitCode, String> {
+    let mut day = None;
+    let mut file = None;
+    let mut rev = None;
+    let mut action = Vec::new();
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--file" => file = Some(iter.next().ok_or("--file needs a path")?.clone()),
+            "--rev" => rev = Some(iter.next().ok_or("--rev needs a git revision")?.as_str()),
+            _ if day.is_none() => day = Some(arg.as_str()),
+            _ => action.push(arg.as_str()),
+        }
+    }
+    let day = day.ok_or("missing day")?;
+    let number: u32 = day.parse().map_err(|_| format!("invalid day '{}'", day))?;
+    let path = format!("{}/day{:02}.jsonl", aoc2025::transcript::DIR, number);
+    let transcript = aoc2025::transcript::Transcript::load(&path)?;
+    let file = file.unwrap_or_else(|| format!("src/day{:02}.rs", number));
+    let history = History::replay(&transcript, &file);
+    if history.versions.is_empty() {
+        let changed = history::changed_files(&transcript);
+        return Err(format!("{} never appears in {}; it changed {}", file, path, changed.join(", ")));
+    }
+    for problem in &history.problems {
+        eprintln!("warning: {}", problem);
+    }
+
+    let version = |arg: &str| {
+        let n: usize = arg.parse().map_err(|_| format!("invalid version '{}'", arg))?;
+        history.get(n).ok_or_else(|| format!("{} has versions 1 to {}", file, history.versions.len()))
+    };
+    match action[..] {
+        [] => {
+            println!("{}: {} versions", file, history.versions.len());
+            let mut previous = "";
+            for v in &history.versions {
+                let (added, removed) = history::line_changes(previous, &v.content);
+                println!(
+                    "{:>3}  {}  {:<8}  {:>4} lines  +{} -{}",
+                    v.number,
+                    v.timestamp,
+                    v.change,
+                    v.content.lines().count(),
+                    added,
+                    removed
+                );
+                previous = &v.content;
+            }
+            for checkpoint in &history.checkpoints {
+                let at = history.at(checkpoint.time).map_or("-".to_string(), |v| v.number.to_string());
+                println!("checkpoint v{} at {}: version {}", checkpoint.version, checkpoint.time, at);
+            }
+        }
+        ["show", n] => print!("{}", version(n)?.content),
+        ["diff", a, b] => {
+            let (a, b) = (version(a)?, version(b)?);
+            let names = (format!("{} v{}", file, a.number), format!("{} v{}", file, b.number));
+            print!("{}", history::diff(&a.content, &b.content, &names.0, &names.1));
+        }
+        ["check"] => {
+            let rev = match rev {
+                Some(rev) => rev.to_string(),
+                None => first_commit(&file)?,
+            };
+            let committed = git(&["show", &format!("{}:{}", rev, file)])?;
+            let last = history.latest().expect("versions is not empty");
+            if committed == last.content {
+                println!("{} v{} matches {} at {}", file, last.number, file, rev);
+                return Ok(ExitCode::SUCCESS);
+            }
+            let names = (format!("{} v{}", file, last.number), format!("{} at {}", file, rev));
+            let diff = history::diff(&last.content, &committed, &names.0, &names.1);
+            if diff.is_empty() {
+                println!("{} v{} differs from {} only in line endings", file, last.number, rev);
+            } else {
+                print!("{}", diff);
+            }
+            return Ok(ExitCode::FAILURE);
+        }
+        _ => return Err("expected show <n>, diff <a> <b> or check".to_string()),
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+// Ranked matches from the transcripts, tasks and sources.
+fn cmd_search(args: &[String]) -> Result<ExitCode, String> {
+    let mut limit = 10;
+    let mut words = Vec::new();
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--limit" => limit = parse_count(iter.next(), "--limit")?,
+            _ => words.push(arg.as_str()),
+        }
+    }
+    if words.is_empty() {
+        return Err("expected a query".to_string());
+    }
+
+    let index = search::Index::new(search::collect(std::path::Path::new(""))?);
+    let hits = index.search(&words.join(" "), limit);
+    if hits.is_empty() {
+        println!("no matches");
+    }
+    for hit in &hits {
+        let day = hit.document.day.map_or("-".to_string(), |d| d.to_string());
+        println!(
+            "{:>5.1}  {:>3}  {:<24}  {:<11}  {}",
+            hit.score,
+            day,
+            hit.document.location(),
+            hit.document.kind,
+            hit.snippet
+        );
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+// Prints a random input for a day, reproducible from the seed.
+fn cmd_gen(args: &[String]) -> Result<ExitCode, String> {
+    let mut day = None;
+    let mut seed = 0;
+    let mut size = None;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--seed" => seed = parse_count(iter.next(), "--seed")? as u64,
+            "--size" => size = Some(parse_count(iter.next(), "--size")?),
+            _ if day.is_none() => day = Some(arg),
+            _ => return Err(format!("unexpected argument '{}'", arg)),
+        }
+    }
+    let day = day.ok_or("missing day")?;
+    let number: u32 = day.parse().map_err(|_| format!("invalid day '{}'", day))?;
+    print!("{}", generate::generate(number, seed, size)?);
+    Ok(ExitCode::SUCCESS)
+}
+
+// Differential testing: solvers against their brute-force oracles on
+// generated inputs. Mismatches are shown shrunk.
+fn cmd_oracle(args: &[String]) -> Result<ExitCode, String> {
+    let mut target = "all";
+    let mut seeds = 100;
+    let mut size = None;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--seeds" => seeds = parse_count(iter.next(), "--seeds")? as u64,
+            "--size" => size = Some(parse_count(iter.next(), "--size")?),
+            _ => target = arg,
+        }
+    }
+    let oracles: Vec<&oracle::Oracle> = select_days(target)?
+        .into_iter()
+        .flat_map(|day| oracle::find(day.number))
+        .collect();
+    if oracles.is_empty() {
+        return Err(format!("no oracle for day {}", target));
+    }
+
+    std::panic::set_hook(Box::new(|_| {}));
+
+    let mut mismatches = Vec::new();
+    println!("{:>3} {:>4}  {:<30} {:>5}  status", "day", "part", "checks", "seeds");
+    for oracle in oracles {
+        let mismatch = oracle.run(0..seeds, size)?;
+        println!(
+            "{:>3} {:>4}  {:<30} {:>5}  {}",
+            oracle.day,
+            oracle.part,
+            oracle.checks,
+            seeds,
+            if mismatch.is_some() { "MISMATCH" } else { "ok" }
+        );
+        mismatches.extend(mismatch.map(|m| (oracle, m)));
+    }
+
+    for (oracle, mismatch) in &mismatches {
+        println!();
+        println!("day {} part {}, seed {}, shrunk to:", oracle.day, oracle.part, mismatch.seed);
+        print!("{}", mismatch.input);
+        println!("expected {}, got {}", mismatch.expected, mismatch.actual);
+    }
+    Ok(if mismatches.is_empty() { ExitCode::SUCCESS } else { ExitCode::FAILURE })
+}
+
+// Feeds mutated inputs to parsers and solvers, which must reject or solve
+// them without panicking or hanging.
+fn cmd_fuzz(args: &[String]) -> Result<ExitCode, String> {
+    let mut target = "all";
+    let mut fuzz_target = fuzz::Target::Solve;
+    let mut runs = 10_000;
+    let mut seed = 0;
+    let mut timeout = 2000;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--target" => {
+                fuzz_target = match iter.next().map(String::as_str) {
+                    Some("parse") => fuzz::Target::Parse,
+                    Some("solve") => fuzz::Target::Solve,
+                    _ => return Err("--target needs parse or solve".to_string()),
+                }
+            }
+            "--runs" => runs = parse_count(iter.next(), "--runs")?,
+            "--seed" => seed = parse_count(iter.next(), "--seed")? as u64,
+            "--timeout" => timeout = parse_count(iter.next(), "--timeout")?.max(1) as u64,
+            _ => target = arg,
+        }
+    }
+    let days = select_days(target)?;
+
+    std::panic::set_hook(Box::new(|_| {}));
+
+    let mut found = Vec::new();
+    println!("{:>3}  {:<6} {:>7} {:>9}  status", "day", "target", "runs", "rejected");
+    for day in days {
+        let timeout = Duration::from_millis(timeout);
+        let name = if fuzz_target == fuzz::Target::Parse { "parse" } else { "solve" };
+        let Some(report) = fuzz::fuzz(day, fuzz_target, seed, runs, timeout) else {
+            println!("{:>3}  {:<6} {:>7} {:>9}  no corpus", day.number, name, "-", "-");
+            continue;
+        };
+        let status = match &report.found {
+            None => "ok",
+            Some(fuzz::Found { failure: fuzz::Failure::Hang, .. }) => "HANG",
+            Some(_) => "PANIC",
+        };
+        println!("{:>3}  {:<6} {:>7} {:>9}  {}", day.number, name, report.runs, report.rejected, status);
+        found.extend(report.found.map(|f| (day.number, f)));
+    }
+
+    for (day, found) in &found {
+        println!();
+        match &found.failure {
+            fuzz::Failure::Panic(message) => println!("day {} {}, shrunk input:", day, message),
+            fuzz::Failure::Hang => println!("day {} ran past {} ms on:", day, timeout),
+        }
+        println!("{:?}", found.input);
+    }
+    Ok(if found.is_empty() { ExitCode::SUCCESS } else { ExitCode::FAILURE })
+}
+
+// The commit that added `path`, which is what the session produced.
+fn first_commit(path: &str) -> Result<String, String> {
+    let log = git(&["log", "--diff-filter=A", "--format=%H", "--", path])?;
+    log.lines().last().map(str::to_string).ok_or_else(|| format!("{} was never committed", path))
+}
+
+fn git(args: &[&str]) -> Result<String, String> {
+    let output = std::process::Command::new("git")
+        .args(args)
+        .output()
+        .map_err(|e| format!("running git: {}", e))?;
+    if !output.status.success() {
+        return Err(format!("git {}: {}", args[0], String::from_utf8_lossy(&output.stderr).trim()));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+fn parse_count(value: Option<&String>, flag: &str) -> Result<usize, String> {
+    value
+        .and_then(|v| v.parse().ok())
+        .ok_or_else(|| format!("{} needs a number", flag))
+}
+
+fn set_source(source: &mut Source, new: Source) -> Result<(), String> {
+    if *source != Source::Data {
+        return Err("--input and --example are mutually exclusive".to_string());
+    }
+    *source = new;
+    Ok(())
+}
+
+// "all" or a single day number.
+fn select_days(target: &str) -> Result<Vec<&'static Day>, String> {
+    if target == "all" {
+        return Ok(days::DAYS.iter().collect());
+    }
+    Ok(vec![parse_day(target)?])
+}
+
+fn parse_day(arg: &str) -> Result<&'static Day, String> {
+    let number: u32 = arg.parse().map_err(|_| format!("invalid day '{}'", arg))?;
+    days::find(number).ok_or_else(|| format!("day {} is not registered", number))
+}
@@ -0,0 +1,424 @@
+use std::collections::HashMap;
+use std::ops::Range;
+
+use aoc2025::solution::Answer;
+use aoc2025::{day01, day02, day03, day09, day10};
+
+use crate::check::catch_panic;
+use crate::{days, generate};
+
+// Slow reference answers, written to be right rather than fast, for the parts
+// whose solvers take shortcuts. Differential testing runs both on generated
+// inputs; a mismatch is shrunk to a small input that still shows it.
+pub struct Oracle {
+    pub day: u32,
+    pub part: u8,
+    // The shortcut being checked.
+    pub checks: &'static str,
+    // Generator size that keeps the reference fast.
+    pub size: usize,
+    // None for inputs the puzzle rules out, or that are too big to brute-force.
+    solve: fn(&str) -> Option<Answer>,
+}
+
+pub const ORACLES: &[Oracle] = &[
+    Oracle { day: 1, part: 2, checks: "zero crossings by division", size: 40, solve: day01_part2 },
+    Oracle { day: 2, part: 1, checks: "patterns capped at 10 digits", size: 2, solve: day02_part1 },
+    Oracle { day: 2, part: 2, checks: "patterns capped at 10 digits", size: 2, solve: day02_part2 },
+    Oracle { day: 3, part: 2, checks: "greedy max_joltage_k", size: 8, solve: day03_part2 },
+    Oracle { day: 9, part: 2, checks: "block validity", size: 4, solve: day09_part2 },
+    Oracle { day: 10, part: 2, checks: "joltage search", size: 3, solve: day10_part2 },
+];
+
+pub fn find(day: u32) -> impl Iterator<Item = &'static Oracle> {
+    ORACLES.iter().filter(move |oracle| oracle.day == day)
+}
+
+// An input on which the solver and the oracle disagree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    pub seed: u64,
+    pub input: String,
+    pub expected: String,
+    // The solver's answer, or its panic.
+    pub actual: String,
+}
+
+impl Oracle {
+    // The solver's and the oracle's answers, if they differ on a valid input.
+    pub fn compare(&self, input: &str) -> Option<(String, String)> {
+        let expected = catch_panic(|| (self.solve)(input)).ok()??.to_string();
+        let day = days::find(self.day).expect("oracle for an unregistered day");
+        let parsed = day.parse(input).ok()?;
+        let actual = match catch_panic(|| day.solve(parsed.as_ref(), self.part)).and_then(|answer| answer) {
+            Ok(answer) => answer.to_string(),
+            Err(e) => e,
+        };
+        (actual != expected).then_some((expected, actual))
+    }
+
+    // The first seed whose generated input shows a mismatch, shrunk.
+    pub fn run(&self, seeds: Range<u64>, size: Option<usize>) -> Result<Option<Mismatch>, String> {
+        for seed in seeds {
+            let input = generate::generate(self.day, seed, Some(size.unwrap_or(self.size)))?;
+            if self.compare(&input).is_some() {
+                let input = shrink(&input, |candidate| self.compare(candidate).is_some());
+                let (expected, actual) = self.compare(&input).expect("shrinking keeps the mismatch");
+                return Ok(Some(Mismatch {
+                    seed,
+                    input,
+                    expected,
+                    actual,
+                }));
+            }
+        }
+        Ok(None)
+    }
+}
+
+// A smaller input for which `fails` still holds: whole lines (or comma
+// separated items, for one-line inputs) are dropped, then numbers are made as
+// small as they can be.
+pub fn shrink(input: &str, fails: impl Fn(&str) -> bool) -> String {
+    let separator = if input.trim_end().contains('\n') { "\n" } else { "," };
+    let mut units: Vec<String> = input.trim_end().split(separator).map(str::to_string).collect();
+    let join = |units: &[String]| units.join(separator) + "\n";
+
+    let mut chunk = units.len() / 2;
+    while chunk > 0 {
+        let mut start = 0;
+        let mut removed = false;
+        while start + chunk <= units.len() {
+            let mut candidate = units.clone();
+            candidate.drain(start..start + chunk);
+            if !candidate.is_empty() && fails(&join(&candidate)) {
+                units = candidate;
+                removed = true;
+            } else {
+                start += chunk;
+            }
+        }
+        if !removed {
+            chunk /= 2;
+        }
+    }
+
+    // Numbers are lowered everywhere they appear at once first, which keeps
+    // shared coordinates shared, then one at a time.
+    let mut current = join(&units);
+    for everywhere in [true, false] {
+        while let Some(smaller) = lower_a_number(&current, everywhere, &fails) {
+            current = smaller;
+        }
+    }
+    current
+}
+
+// `text` with one number (or every copy of it) made smaller, as small as
+// `fails` allows: 0 first, then ever smaller steps down.
+fn lower_a_number(text: &str, everywhere: bool, fails: &impl Fn(&str) -> bool) -> Option<String> {
+    let numbers = numbers(text);
+    for &(start, end) in &numbers {
+        let Ok(value) = text[start..end].parse::<u64>() else { continue };
+        let steps = std::iter::successors(Some(value), |&step| (step > 1).then_some(step / 2));
+        for smaller in steps.map(|step| value - step).filter(|&smaller| smaller < value) {
+            let mut candidate = String::new();
+            let mut last = 0;
+            for &(s, e) in &numbers {
+                if (s, e) == (start, end) || (everywhere && text[s..e] == text[start..end]) {
+                    candidate.push_str(&text[last..s]);
+                    candidate.push_str(&smaller.to_string());
+                    last = e;
+                }
+            }
+            candidate.push_str(&text[last..]);
+            if fails(&candidate) {
+                return Some(candidate);
+            }
+        }
+    }
+    None
+}
+
+// Byte ranges of the runs of digits in `text`.
+fn numbers(text: &str) -> Vec<(usize, usize)> {
+    let mut ranges = Vec::new();
+    let mut start = None;
+    for (i, c) in text.char_indices().chain([(text.len(), ' ')]) {
+        match (c.is_ascii_digit(), start) {
+            (true, None) => start = Some(i),
+            (false, Some(s)) => {
+                ranges.push((s, i));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    ranges
+}
+
+// One click at a time.
+fn day01_part2(input: &str) -> Option<Answer> {
+    let mut position: i32 = 50;
+    let mut count = 0;
+    for (dir, dist) in day01::parse(input).ok()? {
+        let step = if dir == 'L' { -1 } else { 1 };
+        for _ in 0..dist {
+            position = (position + step).rem_euclid(100);
+            if position == 0 {
+                count += 1;
+            }
+        }
+    }
+    Some(count.into())
+}
+
+// Every ID in every range, at most ten million of them.
+fn day02_ids(input: &str, invalid: fn(&str) -> bool) -> Option<Answer> {
+    let ranges = day02::parse(input).ok()?;
+    if ranges.len() > 10_000_000 {
+        return None;
+    }
+    let sum: u64 = ranges.iter().flatten().filter(|id| invalid(&id.to_string())).sum();
+    Some(sum.into())
+}
+
+fn day02_part1(input: &str) -> Option<Answer> {
+    day02_ids(input, |id| {
+        let (a, b) = id.split_at(id.len() / 2);
+        id.len() % 2 == 0 && a == b
+    })
+}
+
+fn day02_part2(input: &str) -> Option<Answer> {
+    day02_ids(input, |id| {
+        (1..id.len()).any(|len| id.len() % len == 0 && id[..len].repeat(id.len() / len) == id)
+    })
+}
+
+// Largest 12-digit subsequence by dynamic programming: best[j] is the largest
+// number made of j digits from the batteries seen so far.
+fn day03_part2(input: &str) -> Option<Answer> {
+    let mut total = 0;
+    for bank in day03::parse(input).ok()? {
+        if bank.len() < 12 {
+            return None;
+        }
+        let mut best = [None::<u64>; 13];
+        best[0] = Some(0);
+        for &digit in &bank {
+            for j in (1..=12).rev() {
+                if let Some(prefix) = best[j - 1] {
+                    let value = prefix * 10 + digit as u64;
+                    best[j] = Some(best[j].map_or(value, |b: u64| b.max(value)));
+                }
+            }
+        }
+        total += best[12]?;
+    }
+    Some(total.into())
+}
+
+// Every tile of every rectangle, checked against the polygon. Tiles between
+// two neighbouring corner coordinates all behave the same, so one of each
+// stands for the rest.
+fn day09_part2(input: &str) -> Option<Answer> {
+    let corners: Vec<(i64, i64)> = day09::parse(input).ok()?.iter().map(|p| (p.x, p.y)).collect();
+    if !simple_rectilinear(&corners) {
+        return None;
+    }
+    let representatives = |values: Vec<i64>| {
+        let mut values = values;
+        values.sort();
+        values.dedup();
+        let mut all = Vec::new();
+        for (i, &v) in values.iter().enumerate() {
+            all.push(v);
+            if values.get(i + 1).is_some_and(|&next| v + 1 < next) {
+                all.push(v + 1);
+            }
+        }
+        all
+    };
+    let xs = representatives(corners.iter().map(|c| c.0).collect());
+    let ys = representatives(corners.iter().map(|c| c.1).collect());
+
+    let mut best = 0;
+    for (i, &a) in corners.iter().enumerate() {
+        for &b in &corners[i + 1..] {
+            let (x1, x2) = (a.0.min(b.0), a.0.max(b.0));
+            let (y1, y2) = (a.1.min(b.1), a.1.max(b.1));
+            let area = (x2 - x1 + 1) * (y2 - y1 + 1);
+            if area <= best {
+                continue;
+            }
+            let inside = xs
+                .iter()
+                .filter(|&&x| (x1..=x2).contains(&x))
+                .all(|&x| ys.iter().filter(|&&y| (y1..=y2).contains(&y)).all(|&y| in_polygon((x, y), &corners)));
+            if inside {
+                best = area;
+            }
+        }
+    }
+    Some(best.into())
+}
+
+// Consecutive corners alternate between horizontal and vertical sides, and
+// sides other than neighbours never touch. They may run on adjacent tiles.
+fn simple_rectilinear(corners: &[(i64, i64)]) -> bool {
+    let n = corners.len();
+    if n < 4 || n % 2 == 1 {
+        return false;
+    }
+    let side = |i: usize| (corners[i], corners[(i + 1) % n]);
+    for i in 0..n {
+        let ((a, b), (_, c)) = (side(i), side((i + 1) % n));
+        let horizontal = a.1 == b.1 && a.0 != b.0;
+        let vertical = a.0 == b.0 && a.1 != b.1;
+        if !(horizontal || vertical) || horizontal == (b.1 == c.1) {
+            return false;
+        }
+    }
+    for i in 0..n {
+        for j in i + 2..n {
+            if (j + 1) % n == i {
+                continue;
+            }
+            let ((a, b), (c, d)) = (side(i), side(j));
+            // How far apart the sides' spans are along each axis.
+            let gap = |p: i64, q: i64, r: i64, s: i64| p.min(q).max(r.min(s)) - p.max(q).min(r.max(s));
+            let (x, y) = (gap(a.0, b.0, c.0, d.0), gap(a.1, b.1, c.1, d.1));
+            if x <= 0 && y <= 0 {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+// On a side, or left of an odd number of vertical sides (counting each
+// side's lower end but not its upper one).
+fn in_polygon(point: (i64, i64), corners: &[(i64, i64)]) -> bool {
+    let (x, y) = point;
+    let n = corners.len();
+    let mut crossings = 0;
+    for i in 0..n {
+        let (a, b) = (corners[i], corners[(i + 1) % n]);
+        let (x1, x2, y1, y2) = (a.0.min(b.0), a.0.max(b.0), a.1.min(b.1), a.1.max(b.1));
+        if (x1..=x2).contains(&x) && (y1..=y2).contains(&y) {
+            return true;
+        }
+        if a.0 == b.0 && a.0 > x && (y1..y2).contains(&y) {
+            crossings += 1;
+        }
+    }
+    crossings % 2 == 1
+}
+
+fn day10_part2(input: &str) -> Option<Answer> {
+    let mut total = 0;
+    for machine in day10::parse(input).ok()? {
+        total += fewest_presses(&machine.buttons, &machine.joltage)?;
+    }
+    Some(total.into())
+}
+
+// Memoised search over the joltage still to reach: some button has to raise
+// the lowest light that isn't there yet, so every button that fits is tried.
+// None if nothing reaches the joltage, or if there are too many states.
+fn fewest_presses(buttons: &[Vec<usize>], joltage: &[i64]) -> Option<i64> {
+    const STATES: usize = 50_000;
+
+    fn search(left: &[i64], buttons: &[Vec<usize>], memo: &mut HashMap<Vec<i64>, Option<i64>>) -> Option<i64> {
+        let Some(light) = left.iter().position(|&j| j > 0) else { return Some(0) };
+        if let Some(&known) = memo.get(left) {
+            return known;
+        }
+        if memo.len() > STATES {
+            return None;
+        }
+        let mut best = None;
+        for button in buttons.iter().filter(|b| b.contains(&light) && b.iter().all(|&l| left[l] > 0)) {
+            let mut next = left.to_vec();
+            button.iter().for_each(|&l| next[l] -= 1);
+            if let Some(presses) = search(&next, buttons, memo) {
+                best = Some(best.map_or(presses + 1, |b: i64| b.min(presses + 1)));
+            }
+        }
+        memo.insert(left.to_vec(), best);
+        best
+    }
+
+    let mut memo = HashMap::new();
+    let presses = search(joltage, buttons, &mut memo);
+    // Answers found after the budget ran out may have missed better ones.
+    (memo.len() <= STATES).then_some(presses)?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_oracles_agree_with_examples() {
+        let cases = [
+            (1, 2, "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82\n", 6i64),
+            (2, 1, "11-22,95-115,998-1012,1188511880-1188511890,222220-222224", 1188735249),
+            (3, 2, "987654321111111\n811111111111119\n234234234234278\n818181911112111\n", 3121910778619),
+            (9, 2, "7,1\n11,1\n11,7\n9,7\n9,5\n2,5\n2,3\n7,3\n", 24),
+            (10, 2, "[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}\n", 10),
+        ];
+        for (day, part, input, expected) in cases {
+            let oracle = find(day).find(|o| o.part == part).unwrap();
+            assert_eq!((oracle.solve)(input), Some(Answer::from(expected)), "day {}", day);
+        }
+        assert_eq!(day02_part2("11-22,95-115,998-1012"), Some(Answer::from(11 + 22 + 99 + 111 + 999 + 1010)));
+        // Seven buttons more than lights, so seven free press counts.
+        let machine = "[....] (1,2,3) (0,1,2) (0,1) (1) (0,1,2,3) (1,2) (3) (0,3) (2) (0) (0,2,3) {8,6,7,4}\n";
+        assert_eq!(day10_part2(machine), Some(Answer::from(8)));
+    }
+
+    #[test]
+    fn test_solvers_match_oracles() {
+        for oracle in ORACLES {
+            let mismatch = oracle.run(0..10, None).unwrap();
+            assert_eq!(mismatch, None, "day {} part {}", oracle.day, oracle.part);
+        }
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        assert_eq!(day03_part2("12345\n"), None);
+        // A side that doubles back on itself.
+        assert_eq!(day09_part2("0,0\n5,0\n5,5\n5,2\n"), None);
+        assert_eq!(day10_part2("[.#] (0) {1,1}\n"), None);
+    }
+
+    #[test]
+    fn test_shrink() {
+        // Fails whenever some line's distance is above 500.
+        let fails = |input: &str| day01::parse(input).unwrap().iter().any(|&(_, dist)| dist > 500);
+        let input = "L12\nR777\nL3\nR999\nL600\nR4\n";
+        assert_eq!(shrink(input, fails), "L501\n");
+
+        let fails = |input: &str| input.split(',').count() >= 2;
+        assert_eq!(shrink("11-22,95-115,998-1012\n", fails), "0-0,0-0\n");
+    }
+
+    #[test]
+    fn test_finds_and_shrinks_a_mismatch() {
+        // Claims every rectangle is valid, so it finds the part 1 answer.
+        let oracle = Oracle {
+            day: 9,
+            part: 1,
+            checks: "",
+            size: 3,
+            solve: day09_part2,
+        };
+        let mismatch = oracle.run(0..10, None).unwrap().unwrap();
+        let largest = numbers(&mismatch.input).iter().map(|&(s, e)| mismatch.input[s..e].parse::<u64>().unwrap()).max();
+        assert!(largest < Some(20), "{}", mismatch.input);
+        assert!(mismatch.expected.parse::<i64>().unwrap() < mismatch.actual.parse::<i64>().unwrap());
+    }
+}
@@ -0,0 +1,114 @@
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::check::catch_panic;
+use crate::days::Day;
+
+// One part of one day. Parsed inputs can't cross threads, so each job parses
+// its own copy.
+pub struct Job {
+    pub day: &'static Day,
+    pub part: u8,
+    // Named in parse errors.
+    pub source: String,
+    // The input, or why it couldn't be read.
+    pub input: Result<String, String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartRun {
+    pub day: u32,
+    pub part: u8,
+    // The answer, or the read error, parse error or panic that replaced it.
+    pub answer: Result<String, String>,
+    // Parsing and solving together.
+    pub elapsed: Duration,
+}
+
+impl Job {
+    fn run(&self) -> PartRun {
+        let start = Instant::now();
+        let answer = self.input.clone().and_then(|input| {
+            catch_panic(|| {
+                let parsed = self.day.parse(&input).map_err(|e| e.in_file(&self.source).to_string())?;
+                Ok(self.day.solve(parsed.as_ref(), self.part)?.to_string())
+            })
+            .and_then(|answer| answer)
+        });
+        PartRun {
+            day: self.day.number,
+            part: self.part,
+            answer,
+            elapsed: start.elapsed(),
+        }
+    }
+}
+
+// Runs the jobs on `threads` workers, each taking the next job as it finishes
+// one. A failing job doesn't stop the others. Runs come back in day and part
+// order.
+pub fn run_all(jobs: &[Job], threads: usize) -> Vec<PartRun> {
+    let next = AtomicUsize::new(0);
+    let runs = Mutex::new(Vec::with_capacity(jobs.len()));
+    thread::scope(|scope| {
+        for _ in 0..threads.clamp(1, jobs.len().max(1)) {
+            scope.spawn(|| {
+                while let Some(job) = jobs.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let run = job.run();
+                    runs.lock().expect("runs are pushed without panicking").push(run);
+                }
+            });
+        }
+    });
+    let mut runs = runs.into_inner().expect("runs are pushed without panicking");
+    runs.sort_by_key(|run| (run.day, run.part));
+    runs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+
+    fn job(day: u32, part: u8, input: Result<&str, &str>) -> Job {
+        Job {
+            day: days::find(day).unwrap(),
+            part,
+            source: "test".to_string(),
+            input: input.map(str::to_string).map_err(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_run_all() {
+        let example = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82\n";
+        let jobs = [
+            job(1, 2, Ok(example)),
+            job(1, 1, Ok(example)),
+            job(2, 1, Ok("11-22,95")),
+            job(3, 1, Err("data/03.txt: missing")),
+            // Parses, but no presses reach the joltage
+            job(10, 2, Ok("[.#] (1) {1,1}")),
+            // There is no part 3, so solving it panics
+            job(1, 3, Ok(example)),
+        ];
+        for threads in [1, 3, 16] {
+            let runs = run_all(&jobs, threads);
+            let answers: Vec<_> = runs.iter().map(|run| (run.day, run.part, run.answer.clone())).collect();
+            assert_eq!(
+                answers,
+                [
+                    (1, 1, Ok("3".to_string())),
+                    (1, 2, Ok("6".to_string())),
+                    (1, 3, Err("panicked: there is no part 3".to_string())),
+                    (2, 1, Err("test:1:7: expected range like 11-22".to_string())),
+                    (3, 1, Err("data/03.txt: missing".to_string())),
+                    (10, 2, Err("no presses reach some machine's joltage".to_string())),
+                ]
+            );
+        }
+        assert_eq!(run_all(&[], 4), []);
+    }
+}
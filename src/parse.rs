@@ -0,0 +1,141 @@
+use std::fmt;
+use std::str::FromStr;
+
+// Where and why an input failed to parse. Line and column are 1-based, the
+// column counted in characters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub file: Option<String>,
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, expected: impl Into<String>) -> Self {
+        ParseError {
+            file: None,
+            line,
+            column,
+            expected: expected.into(),
+        }
+    }
+
+    // Error pointing at the start of `part`, which must be a slice of `input`
+    // (as returned by lines(), split() and friends).
+    pub fn at(input: &str, part: &str, expected: impl Into<String>) -> Self {
+        let offset = (part.as_ptr() as usize).wrapping_sub(input.as_ptr() as usize);
+        assert!(offset <= input.len(), "part is not a slice of input");
+
+        let before = &input[..offset];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let column = before[line_start..].chars().count() + 1;
+        ParseError::new(line, column, expected)
+    }
+
+    pub fn in_file(mut self, file: impl Into<String>) -> Self {
+        self.file = Some(file.into());
+        self
+    }
+
+    // The error message followed by the offending line and a caret under the
+    // column, rustc style.
+    pub fn snippet(&self, input: &str) -> String {
+        let source = input.lines().nth(self.line.saturating_sub(1)).unwrap_or("");
+        let number = self.line.to_string();
+        let gutter = " ".repeat(number.len());
+        let pad = " ".repeat(self.column.saturating_sub(1));
+        format!(
+            "{}\n{} |\n{} | {}\n{} | {}^ expected {}",
+            self, gutter, number, source, gutter, pad, self.expected
+        )
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(file) = &self.file {
+            write!(f, "{}:", file)?;
+        }
+        write!(f, "{}:{}: expected {}", self.line, self.column, self.expected)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+// Parses `part` (a slice of `input`) as a number, reporting its position on
+// failure.
+pub fn number<T: FromStr>(input: &str, part: &str, expected: &str) -> Result<T, ParseError> {
+    part.parse().map_err(|_| ParseError::at(input, part, expected))
+}
+
+// A non-empty rectangular character grid made only of `allowed` characters.
+pub fn grid(input: &str, allowed: &str) -> Result<Vec<Vec<char>>, ParseError> {
+    let mut rows: Vec<Vec<char>> = Vec::new();
+    for line in input.lines() {
+        let row: Vec<char> = line.chars().collect();
+        if let Some(col) = row.iter().position(|c| !allowed.contains(*c)) {
+            let expected = format!("one of '{}'", allowed);
+            return Err(ParseError::new(rows.len() + 1, col + 1, expected));
+        }
+        if let Some(first) = rows.first()
+            && row.len() != first.len()
+        {
+            let expected = format!("row of width {}", first.len());
+            return Err(ParseError::new(rows.len() + 1, row.len().min(first.len()) + 1, expected));
+        }
+        rows.push(row);
+    }
+    if rows.is_empty() || rows[0].is_empty() {
+        return Err(ParseError::new(1, 1, "a non-empty grid"));
+    }
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_at() {
+        let input = "L68\nL30\nRx8\n";
+        let line = input.lines().nth(2).unwrap();
+        let err = ParseError::at(input, &line[1..], "distance");
+        assert_eq!(err, ParseError::new(3, 2, "distance"));
+        assert_eq!(err.to_string(), "3:2: expected distance");
+    }
+
+    #[test]
+    fn test_at_end_of_input() {
+        let input = "1-2";
+        let err = ParseError::at(input, &input[3..], "blank line");
+        assert_eq!((err.line, err.column), (1, 4));
+    }
+
+    #[test]
+    fn test_snippet() {
+        let input = "L68\nL30\nRx8\n";
+        let err = ParseError::new(3, 2, "distance").in_file("data/01.txt");
+        assert_eq!(
+            err.snippet(input),
+            "data/01.txt:3:2: expected distance\n  |\n3 | Rx8\n  |  ^ expected distance"
+        );
+    }
+
+    #[test]
+    fn test_number() {
+        let input = "12,ab";
+        let parts: Vec<&str> = input.split(',').collect();
+        assert_eq!(number::<u32>(input, parts[0], "n"), Ok(12));
+        assert_eq!(number::<u32>(input, parts[1], "n"), Err(ParseError::new(1, 4, "n")));
+    }
+
+    #[test]
+    fn test_grid() {
+        assert_eq!(grid(".@\n@.\n", ".@"), Ok(vec![vec!['.', '@'], vec!['@', '.']]));
+        assert_eq!(grid(".@\n@x\n", ".@"), Err(ParseError::new(2, 2, "one of '.@'")));
+        assert_eq!(grid(".@\n@\n", ".@"), Err(ParseError::new(2, 2, "row of width 2")));
+        assert_eq!(grid("", ".@"), Err(ParseError::new(1, 1, "a non-empty grid")));
+    }
+}
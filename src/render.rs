@@ -0,0 +1,514 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use aoc2025::json::Value;
+use aoc2025::transcript::{self, Block, Message, Role, ToolResult, Transcript, Turn, Usage};
+
+use crate::stats;
+
+// Renders the session logs as self-contained HTML pages: one per session
+// plus a combined page with an index. Tool calls and thinking collapse with
+// <details>, so the pages need no script.
+
+pub const COMBINED: &str = "combined_transcripts.html";
+
+const STYLE: &str = "
+body { font-family: system-ui, sans-serif; line-height: 1.5; max-width: 1100px; margin: 0 auto; padding: 1em; color: #333; background: #fafafa; }
+h1 { font-size: 1.5em; } h2 { font-size: 1.25em; margin-top: 2em; }
+a { color: #1565c0; }
+table.index { border-collapse: collapse; }
+table.index th, table.index td { padding: 0.2em 0.8em; border-bottom: 1px solid #e0e0e0; text-align: right; }
+table.index td.title, table.index th.title { text-align: left; }
+.meta { color: #666; font-size: 0.9em; }
+.turn { border-top: 2px solid #e0e0e0; margin-top: 1.5em; padding-top: 0.5em; }
+.turn-header { color: #666; font-size: 0.85em; }
+.message { margin: 0.6em 0; padding: 0.4em 1em; border-left: 4px solid; background: #fff; border-radius: 4px; }
+.user { border-color: #ff9800; }
+.assistant { border-color: #9c27b0; }
+.notice { border-color: #bbb; color: #666; font-style: italic; }
+details { margin: 0.4em 0; background: #fff; border: 1px solid #e0e0e0; border-radius: 4px; }
+details > summary { cursor: pointer; padding: 0.2em 0.6em; }
+details > div { padding: 0 0.8em 0.4em; }
+details.tool { border-left: 4px solid #4caf50; }
+details.tool.error { border-left-color: #f44336; }
+details.thinking { border-left: 4px solid #bbb; color: #555; }
+.tool-name { font-weight: bold; }
+pre { background: #f5f1e8; padding: 0.6em; overflow-x: auto; font-size: 0.85em; border-radius: 4px; }
+code { font-family: 'Fira Code', Menlo, Consolas, monospace; }
+.result pre { background: #f1f8f1; max-height: 30em; }
+.error .result pre { background: #fdecea; }
+.ins { background: #e6ffec; display: block; } .del { background: #ffebe9; display: block; }
+.kw { color: #a626a4; } .str { color: #50a14f; } .com { color: #a0a1a7; font-style: italic; }
+.num { color: #986801; } .ty { color: #c18401; } .mac { color: #4078f2; }
+.todo-completed { text-decoration: line-through; color: #888; }
+";
+
+pub fn session_file(transcript: &Transcript) -> String {
+    let id = transcript.messages().next().map_or("unknown", |m| m.session_id.as_str());
+    format!("session-{}.html", id)
+}
+
+// Renders every transcripts/dayNN.jsonl under `root` into `out`, returning
+// the files written.
+pub fn render_all(root: &Path, out: &Path) -> Result<Vec<PathBuf>, String> {
+    let days = transcript::load_days(&root.join(transcript::DIR))?;
+    std::fs::create_dir_all(out).map_err(|e| format!("{}: {}", out.display(), e))?;
+    let mut written = Vec::new();
+    let mut write = |name: String, html: String| {
+        let path = out.join(name);
+        std::fs::write(&path, html).map_err(|e| format!("{}: {}", path.display(), e))?;
+        written.push(path);
+        Ok::<_, String>(())
+    };
+    for (day, transcript) in &days {
+        write(session_file(transcript), page(&title(*day, transcript), &session(*day, transcript)))?;
+    }
+    write(COMBINED.to_string(), combined(&days))?;
+    Ok(written)
+}
+
+fn title(day: u32, transcript: &Transcript) -> String {
+    match transcript.title() {
+        Some(summary) => format!("Day {}: {}", day, summary),
+        None => format!("Day {}", day),
+    }
+}
+
+fn page(title: &str, body: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"UTF-8\">\n<title>{}</title>\n<style>{}</style>\n</head>\n<body>\n{}</body>\n</html>\n",
+        escape(title),
+        STYLE,
+        body
+    )
+}
+
+// An index of the days followed by all of them in full.
+pub fn combined(days: &[(u32, Transcript)]) -> String {
+    let mut body = "<h1>Transcripts</h1>\n<table class=\"index\">\n<tr><th>day</th><th class=\"title\">first prompt</th><th>time</th><th>turns</th><th>tool calls</th><th>output tokens</th><th></th></tr>\n".to_string();
+    for (day, transcript) in days {
+        let stats = stats::day_stats(*day, transcript);
+        let prompt = transcript.turns().first().and_then(|t| t.prompt.prompt()).unwrap_or_default();
+        body += &format!(
+            "<tr><td><a href=\"#day{:02}\">{}</a></td><td class=\"title\">{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td><a href=\"{}\">page</a></td></tr>\n",
+            day,
+            day,
+            escape(prompt.lines().next().unwrap_or("")),
+            stats::format_wall_clock(stats.wall_clock),
+            stats.assistant_turns,
+            stats.tool_calls.values().sum::<usize>(),
+            stats.usage.output_tokens,
+            session_file(transcript)
+        );
+    }
+    body += "</table>\n";
+    for (day, transcript) in days {
+        body += &format!("<section id=\"day{:02}\">\n{}</section>\n", day, session(*day, transcript));
+    }
+    page("Transcripts", &body)
+}
+
+pub fn session(day: u32, transcript: &Transcript) -> String {
+    let stats = stats::day_stats(day, transcript);
+    let mut out = format!("<h1>{}</h1>\n", escape(&title(day, transcript)));
+    out += &format!(
+        "<p class=\"meta\">Session {} &middot; started {} &middot; {} &middot; {}</p>\n",
+        escape(transcript.messages().next().map_or("", |m| m.session_id.as_str())),
+        transcript.messages().next().map(|m| m.timestamp.to_string()).unwrap_or_default(),
+        stats::format_wall_clock(stats.wall_clock),
+        usage_line(&stats.usage)
+    );
+
+    let results: HashMap<&str, ToolResult> = transcript.tool_results().map(|r| (r.tool_use_id, r)).collect();
+    for (i, turn) in transcript.turns().iter().enumerate() {
+        out += &render_turn(day, i + 1, turn, &results);
+    }
+    out
+}
+
+fn usage_line(usage: &Usage) -> String {
+    format!(
+        "tokens: {} in, {} out, {} cache write, {} cache read",
+        usage.input_tokens, usage.output_tokens, usage.cache_creation_input_tokens, usage.cache_read_input_tokens
+    )
+}
+
+fn render_turn(day: u32, number: usize, turn: &Turn, results: &HashMap<&str, ToolResult>) -> String {
+    let elapsed = turn.messages().last().map_or(Default::default(), |m| m.timestamp.since(turn.prompt.timestamp));
+    let mut out = format!(
+        "<div class=\"turn\" id=\"day{:02}-turn{}\">\n<div class=\"turn-header\">Turn {} &middot; {} &middot; {} &middot; {}</div>\n",
+        day,
+        number,
+        number,
+        turn.prompt.timestamp,
+        stats::format_wall_clock(elapsed),
+        usage_line(&turn.usage())
+    );
+    out += &format!(
+        "<div class=\"message user\">{}</div>\n",
+        markdown(&turn.prompt.prompt().unwrap_or_default())
+    );
+    for message in &turn.replies {
+        out += &render_message(message, results);
+    }
+    out + "</div>\n"
+}
+
+// Tool results are shown with their calls, so user messages mostly vanish.
+fn render_message(message: &Message, results: &HashMap<&str, ToolResult>) -> String {
+    if message.is_meta {
+        return String::new();
+    }
+    let mut out = String::new();
+    for block in &message.content {
+        match (message.role, block) {
+            (Role::Assistant, Block::Text(text)) => {
+                out += &format!("<div class=\"message assistant\">{}</div>\n", markdown(text));
+            }
+            (Role::User, Block::Text(text)) => {
+                out += &format!("<div class=\"message notice\">{}</div>\n", escape(text));
+            }
+            (_, Block::Thinking(text)) => {
+                out += &format!(
+                    "<details class=\"thinking\"><summary>Thinking</summary><div>{}</div></details>\n",
+                    markdown(text)
+                );
+            }
+            (_, Block::ToolUse { id, name, input }) => out += &render_tool(name, input, results.get(id.as_str())),
+            _ => {}
+        }
+    }
+    out
+}
+
+fn render_tool(name: &str, input: &Value, result: Option<&ToolResult>) -> String {
+    let is_error = result.is_some_and(|r| r.is_error);
+    let mut out = format!(
+        "<details class=\"tool{}\"><summary><span class=\"tool-name\">{}</span> <code>{}</code></summary><div>\n",
+        if is_error { " error" } else { "" },
+        escape(name),
+        escape(&tool_summary(name, input))
+    );
+    let path = input.get("file_path").as_str().unwrap_or("");
+    match name {
+        "Bash" => out += &code_block(input.get("command").as_str().unwrap_or(""), "bash"),
+        "Write" => out += &code_block(input.get("content").as_str().unwrap_or(""), language(path)),
+        "Edit" => {
+            let lines = |key: &str, class: &str| -> String {
+                let text = input.get(key).as_str().unwrap_or("");
+                text.lines().map(|line| format!("<span class=\"{}\">{}</span>", class, escape(line))).collect()
+            };
+            out += &format!("<pre><code>{}{}</code></pre>\n", lines("old_string", "del"), lines("new_string", "ins"));
+        }
+        "TodoWrite" => {
+            out += "<ul>\n";
+            for todo in input.get("todos").as_array() {
+                let status = todo.get("status").as_str().unwrap_or("");
+                out += &format!(
+                    "<li class=\"todo-{}\">{} <span class=\"meta\">({})</span></li>\n",
+                    escape(status),
+                    escape(todo.get("content").as_str().unwrap_or("")),
+                    escape(status)
+                );
+            }
+            out += "</ul>\n";
+        }
+        "Read" | "Glob" | "Grep" => {}
+        _ => out += &code_block(&input.pretty(), "json"),
+    }
+    if let Some(result) = result {
+        out += &format!("<div class=\"result\"><pre><code>{}</code></pre></div>\n", escape(result.content));
+    }
+    out + "</div></details>\n"
+}
+
+// One line saying what a call did.
+fn tool_summary(name: &str, input: &Value) -> String {
+    let field = |key: &str| input.get(key).as_str().map(str::to_string);
+    let summary = match name {
+        "Bash" => field("description").or_else(|| field("command").map(|c| c.lines().next().unwrap_or("").to_string())),
+        "Read" | "Write" | "Edit" => field("file_path"),
+        "Glob" | "Grep" => field("pattern"),
+        "TodoWrite" => Some(format!("{} todos", input.get("todos").as_array().len())),
+        _ => None,
+    };
+    summary.unwrap_or_else(|| {
+        let compact = input.to_string();
+        match compact.char_indices().nth(80) {
+            Some((i, _)) => format!("{}…", &compact[..i]),
+            None => compact,
+        }
+    })
+}
+
+fn language(path: &str) -> &str {
+    match path.rsplit_once('.').map(|(_, extension)| extension) {
+        Some("rs") => "rust",
+        Some("sh") => "bash",
+        Some("py") => "python",
+        Some(extension @ ("toml" | "json" | "md")) => extension,
+        _ => "",
+    }
+}
+
+fn code_block(code: &str, language: &str) -> String {
+    format!("<pre><code class=\"language-{}\">{}</code></pre>\n", language, highlight(code, language))
+}
+
+pub fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+// Just enough markdown for assistant replies: fenced code, headings, lists,
+// tables, paragraphs, `code` and **bold**.
+pub fn markdown(text: &str) -> String {
+    let mut out = String::new();
+    let mut lines = text.lines().peekable();
+    let mut paragraph: Vec<&str> = Vec::new();
+    let flush = |paragraph: &mut Vec<&str>, out: &mut String| {
+        if !paragraph.is_empty() {
+            *out += &format!("<p>{}</p>\n", inline(&paragraph.join("\n")).replace('\n', "<br>\n"));
+            paragraph.clear();
+        }
+    };
+
+    while let Some(line) = lines.next() {
+        let trimmed = line.trim_start();
+        if let Some(info) = trimmed.strip_prefix("```") {
+            flush(&mut paragraph, &mut out);
+            let mut code = Vec::new();
+            for line in lines.by_ref() {
+                if line.trim_start().starts_with("```") {
+                    break;
+                }
+                code.push(line);
+            }
+            out += &code_block(&code.join("\n"), info.trim());
+        } else if let Some(level) = heading_level(trimmed) {
+            flush(&mut paragraph, &mut out);
+            out += &format!("<h{0}>{1}</h{0}>\n", level + 2, inline(trimmed[level..].trim()));
+        } else if list_item(trimmed).is_some() {
+            flush(&mut paragraph, &mut out);
+            let ordered = trimmed.starts_with(|c: char| c.is_ascii_digit());
+            let tag = if ordered { "ol" } else { "ul" };
+            out += &format!("<{}>\n", tag);
+            out += &format!("<li>{}</li>\n", inline(list_item(trimmed).unwrap_or("")));
+            let same_kind = |line: &str| line.starts_with(|c: char| c.is_ascii_digit()) == ordered;
+            while let Some(item) = lines.peek().map(|l| l.trim_start()).filter(|l| same_kind(l)).and_then(list_item) {
+                out += &format!("<li>{}</li>\n", inline(item));
+                lines.next();
+            }
+            out += &format!("</{}>\n", tag);
+        } else if trimmed.starts_with('|') {
+            flush(&mut paragraph, &mut out);
+            let mut rows = vec![trimmed];
+            while let Some(row) = lines.next_if(|l| l.trim_start().starts_with('|')) {
+                rows.push(row.trim_start());
+            }
+            out += &table(&rows);
+        } else if trimmed.is_empty() {
+            flush(&mut paragraph, &mut out);
+        } else {
+            paragraph.push(line);
+        }
+    }
+    flush(&mut paragraph, &mut out);
+    out
+}
+
+fn heading_level(line: &str) -> Option<usize> {
+    let level = line.chars().take_while(|&c| c == '#').count();
+    ((1..=4).contains(&level) && line[level..].starts_with(' ')).then_some(level)
+}
+
+fn list_item(line: &str) -> Option<&str> {
+    if let Some(item) = line.strip_prefix("- ").or_else(|| line.strip_prefix("* ")) {
+        return Some(item);
+    }
+    let digits = line.chars().take_while(char::is_ascii_digit).count();
+    line[digits..].strip_prefix(". ").filter(|_| digits > 0)
+}
+
+// The second row of a markdown table only separates the header.
+fn table(rows: &[&str]) -> String {
+    let cells = |row: &str| -> Vec<String> {
+        row.trim().trim_matches('|').split('|').map(|cell| inline(cell.trim())).collect()
+    };
+    let mut out = "<table class=\"index\">\n".to_string();
+    for (i, row) in rows.iter().enumerate() {
+        if i == 1 && row.chars().all(|c| "|-: ".contains(c)) {
+            continue;
+        }
+        let tag = if i == 0 { "th" } else { "td" };
+        let row: String = cells(row).iter().map(|cell| format!("<{0}>{1}</{0}>", tag, cell)).collect();
+        out += &format!("<tr>{}</tr>\n", row);
+    }
+    out + "</table>\n"
+}
+
+fn inline(text: &str) -> String {
+    let mut out = String::new();
+    for (i, part) in text.split('`').enumerate() {
+        if i % 2 == 1 {
+            out += &format!("<code>{}</code>", escape(part));
+        } else {
+            for (j, plain) in escape(part).split("**").enumerate() {
+                if j % 2 == 1 {
+                    out += &format!("<strong>{}</strong>", plain);
+                } else {
+                    out += plain;
+                }
+            }
+        }
+    }
+    out
+}
+
+const RUST_KEYWORDS: &[&str] = &[
+    "as", "break", "const", "continue", "crate", "dyn", "else", "enum", "false", "fn", "for", "if", "impl", "in", "let",
+    "loop", "match", "mod", "move", "mut", "pub", "ref", "return", "self", "Self", "static", "struct", "super", "trait",
+    "true", "type", "unsafe", "use", "where", "while",
+];
+const SHELL_KEYWORDS: &[&str] = &[
+    "case", "cd", "do", "done", "echo", "elif", "else", "esac", "export", "fi", "for", "function", "if", "in", "then",
+    "while",
+];
+const PYTHON_KEYWORDS: &[&str] = &[
+    "and", "as", "class", "def", "elif", "else", "except", "False", "for", "from", "if", "import", "in", "is", "lambda",
+    "None", "not", "or", "return", "True", "try", "while", "with", "yield",
+];
+
+// Escaped HTML with spans for keywords, strings, comments and numbers. A
+// tokenizer, not a parser: good enough to make code easier to scan.
+pub fn highlight(code: &str, language: &str) -> String {
+    let (keywords, comment): (&[&str], &str) = match language {
+        "rust" | "rs" => (RUST_KEYWORDS, "//"),
+        "bash" | "sh" | "shell" | "console" => (SHELL_KEYWORDS, "#"),
+        "python" | "py" => (PYTHON_KEYWORDS, "#"),
+        "toml" => (&["true", "false"], "#"),
+        "json" => (&["true", "false", "null"], ""),
+        _ => return escape(code),
+    };
+    let rust = keywords == RUST_KEYWORDS;
+    let span = |class: &str, text: &str| format!("<span class=\"{}\">{}</span>", class, escape(text));
+
+    let mut out = String::new();
+    let mut rest = code;
+    while let Some(c) = rest.chars().next() {
+        let len = if !comment.is_empty() && rest.starts_with(comment) {
+            let len = rest.find('\n').unwrap_or(rest.len());
+            out += &span("com", &rest[..len]);
+            len
+        } else if c == '"' || (c == '\'' && !rust) {
+            let mut escaped = false;
+            let end = rest[1..].find(|ch: char| {
+                let close = ch == c && !escaped;
+                escaped = ch == '\\' && !escaped;
+                close
+            });
+            let len = end.map_or(rest.len(), |i| i + 2);
+            out += &span("str", &rest[..len]);
+            len
+        } else if c.is_ascii_digit() {
+            let len = rest.find(|ch: char| !ch.is_ascii_alphanumeric() && ch != '_' && ch != '.').unwrap_or(rest.len());
+            out += &span("num", &rest[..len]);
+            len
+        } else if c.is_alphabetic() || c == '_' {
+            let len = rest.find(|ch: char| !ch.is_alphanumeric() && ch != '_').unwrap_or(rest.len());
+            let word = &rest[..len];
+            if keywords.contains(&word) {
+                out += &span("kw", word);
+            } else if rust && rest[len..].starts_with('!') {
+                out += &span("mac", word);
+            } else if rust && word.starts_with(|ch: char| ch.is_ascii_uppercase()) {
+                out += &span("ty", word);
+            } else {
+                out += &escape(word);
+            }
+            len
+        } else {
+            out += &escape(&c.to_string());
+            c.len_utf8()
+        };
+        rest = &rest[len..];
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_highlight() {
+        assert_eq!(
+            highlight("let x = vec![1]; // \"hi\"", "rust"),
+            "<span class=\"kw\">let</span> x = <span class=\"mac\">vec</span>![<span class=\"num\">1</span>]; \
+             <span class=\"com\">// &quot;hi&quot;</span>"
+        );
+        assert_eq!(
+            highlight("fn f<'a>(s: &'a str) -> String { \"a\\\"b\".into() }", "rust"),
+            "<span class=\"kw\">fn</span> f&lt;'a&gt;(s: &amp;'a str) -&gt; <span class=\"ty\">String</span> { \
+             <span class=\"str\">&quot;a\\&quot;b&quot;</span>.into() }"
+        );
+        assert_eq!(
+            highlight("echo 'it''s' # done", "bash"),
+            "<span class=\"kw\">echo</span> <span class=\"str\">'it'</span><span class=\"str\">'s'</span> \
+             <span class=\"com\"># done</span>"
+        );
+        assert_eq!(highlight("a < b", "text"), "a &lt; b");
+    }
+
+    #[test]
+    fn test_markdown() {
+        let text = "## Plan\n\nUse `Grid<char>` and **sum**\nthe rest.\n\n- one\n- two\n1. first\n\n```rust\nlet x = 1;\n```\n| a | b |\n|---|---|\n| 1 | 2 |";
+        assert_eq!(
+            markdown(text),
+            "<h4>Plan</h4>\n\
+             <p>Use <code>Grid&lt;char&gt;</code> and <strong>sum</strong><br>\nthe rest.</p>\n\
+             <ul>\n<li>one</li>\n<li>two</li>\n</ul>\n\
+             <ol>\n<li>first</li>\n</ol>\n\
+             <pre><code class=\"language-rust\"><span class=\"kw\">let</span> x = <span class=\"num\">1</span>;</code></pre>\n\
+             <table class=\"index\">\n<tr><th>a</th><th>b</th></tr>\n<tr><td>1</td><td>2</td></tr>\n</table>\n"
+        );
+    }
+
+    #[test]
+    fn test_tool_summary() {
+        let input = |json: &str| Value::parse(json).unwrap();
+        assert_eq!(tool_summary("Bash", &input(r#"{"command":"cargo test","description":"Run tests"}"#)), "Run tests");
+        assert_eq!(tool_summary("Bash", &input(r#"{"command":"a\nb"}"#)), "a");
+        assert_eq!(tool_summary("Edit", &input(r#"{"file_path":"src/day01.rs"}"#)), "src/day01.rs");
+        assert_eq!(tool_summary("TodoWrite", &input(r#"{"todos":[{},{}]}"#)), "2 todos");
+        assert_eq!(tool_summary("KillShell", &input(r#"{"shell_id":"010ee7"}"#)), "{\"shell_id\":\"010ee7\"}");
+    }
+
+    #[test]
+    fn test_committed_transcripts() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let days = transcript::load_days(&root.join(transcript::DIR)).unwrap();
+        let (day, transcript) = &days[0];
+        let html = session(*day, transcript);
+        assert!(html.starts_with("<h1>Day 1</h1>"));
+        assert!(html.contains("<div class=\"message user\"><p>solve day 1 part 1</p>\n</div>"));
+        assert!(html.contains("<details class=\"tool\"><summary><span class=\"tool-name\">Bash</span>"));
+        assert_eq!(html.matches("<div class=\"turn\"").count(), transcript.turns().len());
+
+        let combined = combined(&days);
+        assert_eq!(combined.matches("<section id=\"day").count(), 10);
+        assert!(combined.contains(&format!("<a href=\"{}\">page</a>", session_file(transcript))));
+    }
+
+    #[test]
+    fn test_render_all_creates_out() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let out = std::env::temp_dir().join(format!("aoc-render-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&out);
+
+        let written = render_all(root, &out.join("pages")).unwrap();
+        assert_eq!(written.last(), Some(&out.join("pages").join(COMBINED)));
+        assert!(written.iter().all(|path| path.is_file()));
+
+        std::fs::remove_dir_all(&out).unwrap();
+    }
+}
@@ -0,0 +1,220 @@
+use std::fs;
+use std::path::Path;
+
+use aoc2025::task;
+
+// Starting point for a new day; DAY is replaced with the two-digit day.
+const TEMPLATE: &str = r#"use crate::parse::{self, ParseError};
+use crate::solution::{Answer, Solution};
+
+pub struct DayDAY;
+
+impl Solution for DayDAY {
+    type Input = Vec<i64>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, String> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, String> {
+        Ok(part2(input).into())
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+    input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| parse::number(input, line, "number"))
+        .collect()
+}
+
+pub fn part1(numbers: &[i64]) -> i64 {
+    todo!("part 1 for {} numbers", numbers.len())
+}
+
+pub fn part2(numbers: &[i64]) -> i64 {
+    todo!("part 2 for {} numbers", numbers.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Paste the example from tasks/dayDAY-1.md, put its answers in the
+    // tests and drop their ignore attributes
+    const EXAMPLE: &str = "";
+
+    #[test]
+    #[ignore = "no example yet"]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EXAMPLE).unwrap()), 0);
+    }
+
+    #[test]
+    #[ignore = "no example yet"]
+    fn test_part2() {
+        assert_eq!(part2(&parse(EXAMPLE).unwrap()), 0);
+    }
+}
+"#;
+
+pub fn source(day: u32) -> String {
+    TEMPLATE.replace("DAY", &format!("{:02}", day))
+}
+
+// Creates src/dayNN.rs, both task files and the input placeholder under
+// `root`, then registers the day in src/lib.rs and src/days.rs. Nothing is
+// written if any of the files already exists or the day is registered.
+// Returns the paths created.
+pub fn new_day(root: &Path, day: u32) -> Result<Vec<String>, String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("day {} is not between 1 and 25", day));
+    }
+
+    let files = [
+        (format!("src/day{:02}.rs", day), source(day)),
+        (task::path(day, 1), String::new()),
+        (task::path(day, 2), String::new()),
+        (format!("data/{:02}.txt", day), String::new()),
+    ];
+    for (path, _) in &files {
+        if root.join(path).exists() {
+            return Err(format!("{} already exists", path));
+        }
+    }
+
+    let read = |path: &str| fs::read_to_string(root.join(path)).map_err(|e| format!("{}: {}", path, e));
+    let lib_rs = register_module(&read("src/lib.rs")?, day)?;
+    let days_rs = register_day(&read("src/days.rs")?, day)?;
+
+    let write = |path: &str, contents: &str| {
+        let full = root.join(path);
+        if let Some(dir) = full.parent() {
+            fs::create_dir_all(dir).map_err(|e| format!("{}: {}", dir.display(), e))?;
+        }
+        fs::write(&full, contents).map_err(|e| format!("{}: {}", path, e))
+    };
+    for (path, contents) in &files {
+        write(path, contents)?;
+    }
+    write("src/lib.rs", &lib_rs)?;
+    write("src/days.rs", &days_rs)?;
+
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+// Adds `pub mod dayNN;` to the day modules, keeping them in order.
+fn register_module(lib_rs: &str, day: u32) -> Result<String, String> {
+    let line = format!("pub mod day{:02};", day);
+    insert_sorted(lib_rs, &line, |l| l.starts_with("pub mod day"))
+        .ok_or_else(|| format!("day {} is already a module in src/lib.rs", day))
+}
+
+// Adds the day to the `use aoc2025::{...}` import and to DAYS.
+fn register_day(days_rs: &str, day: u32) -> Result<String, String> {
+    let module = format!("day{:02}", day);
+    let entry = format!("    Day::of::<{}::Day{:02}>({}),", module, day, day);
+    let already = || format!("day {} is already registered in src/days.rs", day);
+
+    let with_entry = insert_sorted(days_rs, &entry, |l| l.starts_with("    Day::of::<")).ok_or_else(already)?;
+
+    let import_start = with_entry.find("use aoc2025::{").ok_or("no `use aoc2025::{` in src/days.rs")?;
+    let import_end = import_start + with_entry[import_start..].find('}').ok_or("unclosed import in src/days.rs")?;
+    let names = &with_entry[import_start + "use aoc2025::{".len()..import_end];
+    let mut modules: Vec<&str> = names.split(',').map(str::trim).filter(|m| !m.is_empty()).collect();
+    if modules.contains(&module.as_str()) {
+        return Err(already());
+    }
+    modules.push(&module);
+    modules.sort();
+
+    Ok(format!(
+        "{}use aoc2025::{{{}}}{}",
+        &with_entry[..import_start],
+        modules.join(", "),
+        &with_entry[import_end + 1..]
+    ))
+}
+
+// Inserts `line` among the consecutive lines matching `group`, before the
+// first one that sorts after it. None if the line is already there.
+fn insert_sorted(text: &str, line: &str, group: impl Fn(&str) -> bool) -> Option<String> {
+    let lines: Vec<&str> = text.lines().collect();
+    if lines.contains(&line) {
+        return None;
+    }
+    let first = lines.iter().position(|l| group(l))?;
+    let end = first + lines[first..].iter().take_while(|l| group(l)).count();
+    let at = (first..end).find(|&i| lines[i] > line).unwrap_or(end);
+
+    let mut out: Vec<&str> = lines[..at].to_vec();
+    out.push(line);
+    out.extend(&lines[at..]);
+    Some(out.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_register_module() {
+        let lib_rs = "#![allow(x)]\n\npub mod day01;\npub mod day03;\npub mod grid;\n";
+        assert_eq!(
+            register_module(lib_rs, 2).unwrap(),
+            "#![allow(x)]\n\npub mod day01;\npub mod day02;\npub mod day03;\npub mod grid;\n"
+        );
+        assert_eq!(
+            register_module(lib_rs, 11).unwrap(),
+            "#![allow(x)]\n\npub mod day01;\npub mod day03;\npub mod day11;\npub mod grid;\n"
+        );
+        assert!(register_module(lib_rs, 3).is_err());
+    }
+
+    #[test]
+    fn test_register_day() {
+        let days_rs = "use aoc2025::{day01, day10};\n\npub const DAYS: &[Day] = &[\n    Day::of::<day01::Day01>(1),\n    Day::of::<day10::Day10>(10),\n];\n";
+        assert_eq!(
+            register_day(days_rs, 2).unwrap(),
+            "use aoc2025::{day01, day02, day10};\n\npub const DAYS: &[Day] = &[\n    Day::of::<day01::Day01>(1),\n    Day::of::<day02::Day02>(2),\n    Day::of::<day10::Day10>(10),\n];\n"
+        );
+        assert!(register_day(days_rs, 10).is_err());
+    }
+
+    #[test]
+    fn test_registers_against_the_real_tree() {
+        // The runner's own files must keep the shape new_day() edits.
+        let lib_rs = include_str!("lib.rs");
+        let days_rs = include_str!("days.rs");
+        assert!(register_module(lib_rs, 25).unwrap().contains("pub mod day25;\n"));
+        assert!(register_day(days_rs, 25).unwrap().contains("Day::of::<day25::Day25>(25),\n];"));
+        assert!(register_day(days_rs, 1).is_err());
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        fs::create_dir_all(root.join("src")).unwrap();
+        fs::write(root.join("src/lib.rs"), "pub mod day01;\npub mod grid;\n").unwrap();
+        fs::write(root.join("src/days.rs"), "use aoc2025::{day01};\n\nconst DAYS: &[Day] = &[\n    Day::of::<day01::Day01>(1),\n];\n").unwrap();
+
+        let created = new_day(&root, 2).unwrap();
+        assert_eq!(created, ["src/day02.rs", "tasks/day02-1.md", "tasks/day02-2.md", "data/02.txt"]);
+        let source = fs::read_to_string(root.join("src/day02.rs")).unwrap();
+        assert!(source.contains("impl Solution for Day02 {"));
+        assert_eq!(source.matches("#[ignore").count(), source.matches("#[test]").count());
+        assert!(fs::read_to_string(root.join("src/lib.rs")).unwrap().contains("pub mod day02;"));
+
+        // A second run must not touch anything.
+        fs::write(root.join("src/day02.rs"), "solved").unwrap();
+        assert_eq!(new_day(&root, 2), Err("src/day02.rs already exists".to_string()));
+        assert_eq!(fs::read_to_string(root.join("src/day02.rs")).unwrap(), "solved");
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
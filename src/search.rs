@@ -0,0 +1,372 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::path::Path;
+
+use crate::transcript::{self, Block, Timestamp, Transcript};
+
+// Full-text search over the transcripts, task descriptions and sources.
+// Everything is split into small documents (a message block, a tool call's
+// input or output, a paragraph of a file), indexed in memory and ranked with
+// BM25. Unit tests in the sources count for less, since they're full of
+// made-up text.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    Message,
+    Thinking,
+    ToolInput,
+    ToolOutput,
+    Task,
+    Source,
+    // Source from `#[cfg(test)]` on.
+    Test,
+}
+
+impl fmt::Display for Kind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(match self {
+            Kind::Message => "message",
+            Kind::Thinking => "thinking",
+            Kind::ToolInput => "tool input",
+            Kind::ToolOutput => "tool output",
+            Kind::Task => "task",
+            Kind::Source => "source",
+            Kind::Test => "test",
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Document {
+    pub kind: Kind,
+    pub day: Option<u32>,
+    // The file and, for tasks and sources, the 1-based line the text starts
+    // on.
+    pub path: String,
+    pub line: Option<usize>,
+    pub timestamp: Option<Timestamp>,
+    pub text: String,
+}
+
+impl Document {
+    // Where to look: the time for transcript text, else file:line.
+    pub fn location(&self) -> String {
+        match (self.timestamp, self.line) {
+            (Some(timestamp), _) => timestamp.to_string(),
+            (None, Some(line)) => format!("{}:{}", self.path, line),
+            (None, None) => self.path.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Hit<'a> {
+    pub document: &'a Document,
+    pub score: f64,
+    pub snippet: String,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Index {
+    pub documents: Vec<Document>,
+    // term -> (document, occurrences)
+    postings: HashMap<String, Vec<(usize, u32)>>,
+    lengths: Vec<u32>,
+}
+
+const K1: f64 = 1.2;
+const B: f64 = 0.75;
+const SNIPPET: usize = 100;
+// Scale for the scores of unit tests.
+const TEST_WEIGHT: f64 = 0.5;
+
+impl Index {
+    pub fn new(documents: Vec<Document>) -> Index {
+        let mut postings: HashMap<String, Vec<(usize, u32)>> = HashMap::new();
+        let mut lengths = Vec::with_capacity(documents.len());
+        for (i, document) in documents.iter().enumerate() {
+            let mut counts: HashMap<String, u32> = HashMap::new();
+            let mut length = 0;
+            for term in terms(&document.text) {
+                *counts.entry(term).or_insert(0) += 1;
+                length += 1;
+            }
+            lengths.push(length);
+            for (term, count) in counts {
+                postings.entry(term).or_default().push((i, count));
+            }
+        }
+        Index {
+            documents,
+            postings,
+            lengths,
+        }
+    }
+
+    // Best first, at most `limit` hits.
+    pub fn search(&self, query: &str, limit: usize) -> Vec<Hit<'_>> {
+        let n = self.documents.len() as f64;
+        let average = self.lengths.iter().map(|&l| l as f64).sum::<f64>() / n.max(1.0);
+        let mut query_terms = terms(query);
+        query_terms.sort();
+        query_terms.dedup();
+
+        let mut scores: HashMap<usize, f64> = HashMap::new();
+        for term in &query_terms {
+            let Some(postings) = self.postings.get(term) else { continue };
+            let df = postings.len() as f64;
+            let idf = ((n - df + 0.5) / (df + 0.5) + 1.0).ln();
+            for &(doc, count) in postings {
+                let tf = count as f64;
+                let norm = 1.0 - B + B * self.lengths[doc] as f64 / average;
+                *scores.entry(doc).or_insert(0.0) += idf * tf * (K1 + 1.0) / (tf + K1 * norm);
+            }
+        }
+
+        let mut ranked: Vec<(usize, f64)> = scores
+            .into_iter()
+            .map(|(doc, score)| match self.documents[doc].kind {
+                Kind::Test => (doc, score * TEST_WEIGHT),
+                _ => (doc, score),
+            })
+            .collect();
+        ranked.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+        ranked
+            .into_iter()
+            .take(limit)
+            .map(|(doc, score)| Hit {
+                document: &self.documents[doc],
+                score,
+                snippet: snippet(&self.documents[doc].text, &query_terms),
+            })
+            .collect()
+    }
+}
+
+// Lowercased words and numbers, crudely stemmed so "overflows" finds
+// "overflow". Underscores and punctuation separate words, so
+// `max_joltage_k` and "union-find" match their parts.
+pub fn terms(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| stem(&word.to_lowercase()))
+        .collect()
+}
+
+fn stem(word: &str) -> String {
+    for suffix in ["ing", "ed", "s"] {
+        if let Some(root) = word.strip_suffix(suffix)
+            && root.chars().count() >= 3
+            && !root.ends_with('s')
+        {
+            return root.to_string();
+        }
+    }
+    word.to_string()
+}
+
+// About SNIPPET characters of `text` on one line, around the first word that
+// matches a term.
+fn snippet(text: &str, query_terms: &[String]) -> String {
+    let mut start = 0;
+    let mut offset = 0;
+    for word in text.split_inclusive(|c: char| !c.is_alphanumeric()) {
+        let bare = word.trim_end_matches(|c: char| !c.is_alphanumeric());
+        if !bare.is_empty() && query_terms.contains(&stem(&bare.to_lowercase())) {
+            start = offset;
+            break;
+        }
+        offset += word.len();
+    }
+
+    // A little context before the match.
+    let before: Vec<(usize, char)> = text[..start].char_indices().collect();
+    let from = before.len().saturating_sub(SNIPPET / 4);
+    let begin = before.get(from).map_or(start, |&(i, _)| i);
+    let excerpt: String = text[begin..].chars().take(SNIPPET).collect();
+    let mut line = excerpt.split_whitespace().collect::<Vec<_>>().join(" ");
+    if begin > 0 {
+        line.insert(0, '…');
+    }
+    if begin + excerpt.len() < text.len() {
+        line.push('…');
+    }
+    line
+}
+
+// A day number from names like day07.jsonl, day07-2.md or day07.rs.
+fn day_of(name: &str) -> Option<u32> {
+    name.strip_prefix("day")?.get(..2)?.parse().ok()
+}
+
+pub fn transcript_documents(day: u32, path: &str, transcript: &Transcript) -> Vec<Document> {
+    let mut documents = Vec::new();
+    let mut add = |kind, timestamp, text: String| {
+        if !text.trim().is_empty() {
+            documents.push(Document {
+                kind,
+                day: Some(day),
+                path: path.to_string(),
+                line: None,
+                timestamp: Some(timestamp),
+                text,
+            });
+        }
+    };
+    for message in transcript.messages().filter(|m| !m.is_meta) {
+        for block in &message.content {
+            let (kind, text) = match block {
+                Block::Text(text) => (Kind::Message, text.clone()),
+                Block::Thinking(text) => (Kind::Thinking, text.clone()),
+                Block::ToolUse { name, input, .. } => (Kind::ToolInput, format!("{} {}", name, input)),
+                Block::ToolResult { content, .. } => (Kind::ToolOutput, content.clone()),
+                Block::Other(_) => continue,
+            };
+            add(kind, message.timestamp, text);
+        }
+    }
+    documents
+}
+
+// Paragraphs separated by blank lines, each remembering its first line. In a
+// source, paragraphs from `#[cfg(test)]` on are tests.
+pub fn file_documents(kind: Kind, path: &str, text: &str) -> Vec<Document> {
+    let day = Path::new(path).file_name().and_then(|n| n.to_str()).and_then(day_of);
+    let mut kind = kind;
+    let mut documents = Vec::new();
+    let mut paragraph: Vec<&str> = Vec::new();
+    let mut first = 0;
+    for (i, line) in text.lines().chain([""]).enumerate() {
+        if kind == Kind::Source && line.trim() == "#[cfg(test)]" {
+            kind = Kind::Test;
+        }
+        if line.trim().is_empty() {
+            if !paragraph.is_empty() {
+                documents.push(Document {
+                    kind,
+                    day,
+                    path: path.to_string(),
+                    line: Some(first + 1),
+                    timestamp: None,
+                    text: paragraph.join("\n"),
+                });
+                paragraph.clear();
+            }
+        } else {
+            if paragraph.is_empty() {
+                first = i;
+            }
+            paragraph.push(line);
+        }
+    }
+    documents
+}
+
+// Transcripts, tasks/*.md and src/*.rs under `root`.
+pub fn collect(root: &Path) -> Result<Vec<Document>, String> {
+    let mut documents = Vec::new();
+    for (day, transcript) in transcript::load_days(&root.join(transcript::DIR))? {
+        let path = format!("{}/day{:02}.jsonl", transcript::DIR, day);
+        documents.extend(transcript_documents(day, &path, &transcript));
+    }
+    for (dir, extension, kind) in [("tasks", "md", Kind::Task), ("src", "rs", Kind::Source)] {
+        let entries = std::fs::read_dir(root.join(dir)).map_err(|e| format!("{}: {}", dir, e))?;
+        let mut names: Vec<String> = entries
+            .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+            .filter(|name| name.ends_with(&format!(".{}", extension)))
+            .collect();
+        names.sort();
+        for name in names {
+            let path = format!("{}/{}", dir, name);
+            let text = std::fs::read_to_string(root.join(&path)).map_err(|e| format!("{}: {}", path, e))?;
+            documents.extend(file_documents(kind, &path, &text));
+        }
+    }
+    Ok(documents)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn document(kind: Kind, text: &str) -> Document {
+        Document {
+            kind,
+            day: None,
+            path: "x".to_string(),
+            line: None,
+            timestamp: None,
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_terms() {
+        assert_eq!(terms("Union-Find for max_joltage_k"), ["union", "find", "for", "max", "joltage", "k"]);
+        assert_eq!(terms("Overflows overflowed overflowing"), ["overflow", "overflow", "overflow"]);
+        assert_eq!(terms("uses bus class machines"), ["use", "bus", "class", "machine"]);
+    }
+
+    #[test]
+    fn test_search() {
+        let index = Index::new(vec![
+            document(Kind::Message, "Let me solve this with a simple loop over the input."),
+            document(Kind::Message, "Gaussian elimination overflows i64 here; switching to i128 fixes the overflow."),
+            document(Kind::Source, "fn gaussian(matrix: &mut Vec<Vec<i64>>) {}"),
+            document(Kind::Task, "The input is a list of machines."),
+        ]);
+        let hits = index.search("gaussian elimination overflow", 10);
+        assert_eq!(hits.len(), 2);
+        assert!(hits[0].document.text.starts_with("Gaussian elimination"));
+        assert!(hits[0].score > hits[1].score);
+        assert_eq!(hits[1].document.kind, Kind::Source);
+        assert_eq!(index.search("input", 1).len(), 1);
+        assert!(index.search("nothing matches", 10).is_empty());
+    }
+
+    #[test]
+    fn test_tests_rank_lower() {
+        let text = "fn find(&mut self, x: usize) -> usize";
+        let index = Index::new(vec![document(Kind::Test, text), document(Kind::Source, text)]);
+        let hits = index.search("find", 10);
+        assert_eq!(hits[0].document.kind, Kind::Source);
+        assert_eq!(hits[1].score, hits[0].score * TEST_WEIGHT);
+    }
+
+    #[test]
+    fn test_snippet() {
+        let text = format!("{} the union-find\nstructure {}", "x".repeat(60), "y ".repeat(80));
+        let snippet = snippet(&text, &["union".to_string()]);
+        assert!(snippet.starts_with(&format!("…{} the union-find structure y y", "x".repeat(20))));
+        assert!(snippet.ends_with('…'));
+        assert_eq!(super::snippet("short text", &["text".to_string()]), "short text");
+    }
+
+    #[test]
+    fn test_file_documents() {
+        let documents = file_documents(Kind::Task, "tasks/day07-2.md", "# Title\n\nFirst para\ngoes on\n\n\nSecond\n");
+        let found: Vec<(Option<u32>, String, &str)> =
+            documents.iter().map(|d| (d.day, d.location(), d.text.as_str())).collect();
+        assert_eq!(
+            found,
+            [
+                (Some(7), "tasks/day07-2.md:1".to_string(), "# Title"),
+                (Some(7), "tasks/day07-2.md:3".to_string(), "First para\ngoes on"),
+                (Some(7), "tasks/day07-2.md:7".to_string(), "Second"),
+            ]
+        );
+
+        let source = "fn main() {}\n\n#[cfg(test)]\nmod tests {\n\n    fn helper() {}\n}\n";
+        let kinds: Vec<Kind> = file_documents(Kind::Source, "src/grid.rs", source).iter().map(|d| d.kind).collect();
+        assert_eq!(kinds, [Kind::Source, Kind::Test, Kind::Test]);
+    }
+
+    #[test]
+    fn test_committed_tree() {
+        let index = Index::new(collect(Path::new(env!("CARGO_MANIFEST_DIR"))).unwrap());
+        let hits = index.search("union find", 20);
+        assert!(hits.iter().any(|hit| hit.document.day == Some(8)));
+        assert!(hits.iter().any(|hit| hit.document.kind == Kind::Source));
+        assert!(hits.iter().any(|hit| hit.document.path == "src/union_find.rs"));
+    }
+}
@@ -0,0 +1,249 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use aoc2025::html;
+use aoc2025::submission::Outcome;
+
+use crate::http::{self, Response};
+
+pub const YEAR: u32 = 2025;
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const SESSION_FILE: &str = ".session";
+const USER_AGENT: &str = concat!("aoc2025/", env!("CARGO_PKG_VERSION"), " (personal puzzle runner)");
+
+// Requests to the site are at least this far apart, across runs.
+const MIN_INTERVAL: Duration = Duration::from_secs(5);
+const STAMP_FILE: &str = "data/.last-request";
+
+// How to reach adventofcode.com, and the local state that goes with it.
+pub struct Site {
+    pub base_url: String,
+    session: Option<String>,
+    // Inputs and the rate limit stamp live under here.
+    root: PathBuf,
+    min_interval: Duration,
+}
+
+impl Site {
+    // AOC_BASE_URL overrides the site (for testing against a stub), and the
+    // session token comes from AOC_SESSION or the first line of ./.session.
+    pub fn from_env() -> Site {
+        let base_url = std::env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.to_string());
+        let session = std::env::var("AOC_SESSION")
+            .ok()
+            .or_else(|| fs::read_to_string(SESSION_FILE).ok())
+            .and_then(|s| s.lines().next().map(|line| line.trim().to_string()))
+            .filter(|s| !s.is_empty());
+        Site::new(base_url, session, Path::new(""), MIN_INTERVAL)
+    }
+
+    pub fn new(base_url: String, session: Option<String>, root: &Path, min_interval: Duration) -> Site {
+        Site {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+            root: root.to_path_buf(),
+            min_interval,
+        }
+    }
+
+    pub fn day_url(&self, day: u32) -> String {
+        format!("{}/{}/day/{}", self.base_url, YEAR, day)
+    }
+
+    fn session(&self) -> Result<&str, String> {
+        self.session.as_deref().ok_or_else(|| {
+            format!(
+                "no session token: set AOC_SESSION or put the adventofcode.com session cookie in {}",
+                SESSION_FILE
+            )
+        })
+    }
+
+    // Authenticated GET, after waiting out the rate limit.
+    pub fn get(&self, url: &str) -> Result<Response, String> {
+        let cookie = format!("session={}", self.session()?);
+        self.wait_turn()?;
+        http::get(url, &[("Cookie", &cookie), ("User-Agent", USER_AGENT)])
+    }
+
+    // Authenticated form POST, after waiting out the rate limit.
+    pub fn post(&self, url: &str, form: &[(&str, &str)]) -> Result<Response, String> {
+        let cookie = format!("session={}", self.session()?);
+        self.wait_turn()?;
+        http::post_form(url, &[("Cookie", &cookie), ("User-Agent", USER_AGENT)], form)
+    }
+
+    // Sleeps until min_interval has passed since the last request (from any
+    // run) and records this one.
+    fn wait_turn(&self) -> Result<(), String> {
+        let stamp = self.root.join(STAMP_FILE);
+        let last = fs::read_to_string(&stamp)
+            .ok()
+            .and_then(|s| s.trim().parse().ok())
+            .map(Duration::from_millis);
+        if let Some(last) = last {
+            let wait = (last + self.min_interval).saturating_sub(now());
+            if !wait.is_zero() {
+                eprintln!("waiting {:.1}s between requests", wait.as_secs_f64());
+                thread::sleep(wait);
+            }
+        }
+        if let Some(dir) = stamp.parent() {
+            fs::create_dir_all(dir).map_err(|e| format!("{}: {}", dir.display(), e))?;
+        }
+        // Rounded up, so truncation never makes the next wait short.
+        let millis = now().as_micros().div_ceil(1000);
+        fs::write(&stamp, millis.to_string()).map_err(|e| format!("{}: {}", stamp.display(), e))
+    }
+
+    pub fn input_path(&self, day: u32) -> PathBuf {
+        self.root.join(format!("data/{:02}.txt", day))
+    }
+}
+
+fn now() -> Duration {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default()
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Fetched {
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+// Downloads the day's input to data/NN.txt unless it is already there. An
+// empty file (the placeholder from `aoc new`) counts as missing.
+pub fn fetch_input(site: &Site, day: u32) -> Result<Fetched, String> {
+    let path = site.input_path(day);
+    if fs::metadata(&path).is_ok_and(|m| m.len() > 0) {
+        return Ok(Fetched::Cached(path));
+    }
+
+    let response = site.get(&format!("{}/input", site.day_url(day)))?;
+    match response.status {
+        200 => {}
+        404 => return Err(format!("day {} input is not available (yet)", day)),
+        400 | 500 if response.body.contains("log in") => {
+            return Err("the session token was rejected; log in again and update it".to_string());
+        }
+        status => return Err(format!("fetching day {} input: HTTP {}: {}", day, status, response.body.trim())),
+    }
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| format!("{}: {}", dir.display(), e))?;
+    }
+    fs::write(&path, &response.body).map_err(|e| format!("{}: {}", path.display(), e))?;
+    Ok(Fetched::Downloaded(path))
+}
+
+// The puzzle page. Part 2 is only on it once part 1 is solved, so this needs
+// the session too.
+pub fn fetch_page(site: &Site, day: u32) -> Result<String, String> {
+    let response = site.get(&site.day_url(day))?;
+    match response.status {
+        200 => Ok(response.body),
+        404 => Err(format!("day {} is not available (yet)", day)),
+        status => Err(format!("fetching day {} page: HTTP {}", day, status)),
+    }
+}
+
+// Sends an answer and classifies the response. Unrecognized responses are
+// errors carrying the page's text.
+pub fn submit(site: &Site, day: u32, part: u8, answer: &str) -> Result<Outcome, String> {
+    let level = part.to_string();
+    let response = site.post(&format!("{}/answer", site.day_url(day)), &[("level", &level), ("answer", answer)])?;
+    if response.status != 200 {
+        return Err(format!("submitting day {} part {}: HTTP {}", day, part, response.status));
+    }
+    Outcome::classify(&response.body).ok_or_else(|| {
+        let text = html::articles(&response.body).join("\n\n");
+        format!("unrecognized response to the submission:\n{}", if text.is_empty() { &response.body } else { &text })
+    })
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use crate::http::stub;
+
+    // A scratch directory standing in for the repo root.
+    pub fn scratch(name: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("aoc-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).unwrap();
+        root
+    }
+
+    pub fn site(url: &str, root: &Path) -> Site {
+        Site::new(url.to_string(), Some("abc123".to_string()), root, Duration::ZERO)
+    }
+
+    #[test]
+    fn test_fetch_input() {
+        let root = scratch("fetch");
+        let server = stub::serve(vec![(200, "1,2,3\n"), (404, "Not Found")]);
+        let site = site(&server.url, &root);
+
+        let path = root.join("data/07.txt");
+        assert_eq!(fetch_input(&site, 7), Ok(Fetched::Downloaded(path.clone())));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1,2,3\n");
+
+        // Cached: the stub would answer 404 if asked again.
+        assert_eq!(fetch_input(&site, 7), Ok(Fetched::Cached(path)));
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].starts_with("GET /2025/day/7/input HTTP/1.0\r\n"));
+        assert!(requests[0].contains("\r\nCookie: session=abc123\r\n"));
+
+        assert!(fetch_input(&site, 8).unwrap_err().contains("not available"));
+        assert!(!root.join("data/08.txt").exists());
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_errors() {
+        let root = scratch("fetch-errors");
+        let server = stub::serve(vec![(400, "Puzzle inputs differ by user.  Please log in to get your puzzle input.")]);
+        let site = site(&server.url, &root);
+        assert!(fetch_input(&site, 1).unwrap_err().contains("session token was rejected"));
+
+        // An empty placeholder is replaced, but only with a session.
+        fs::create_dir_all(root.join("data")).unwrap();
+        fs::write(root.join("data/01.txt"), "").unwrap();
+        let anonymous = Site::new(server.url.clone(), None, &root, Duration::ZERO);
+        assert!(fetch_input(&anonymous, 1).unwrap_err().contains("AOC_SESSION"));
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_submit() {
+        let root = scratch("submit");
+        let server = stub::serve(vec![
+            (200, "<article><p>That's not the right answer; your answer is too low.</p></article>"),
+            (200, "<article><p>Welcome to the new site!</p></article>"),
+        ]);
+        let site = site(&server.url, &root);
+        assert_eq!(submit(&site, 3, 2, "357"), Ok(Outcome::TooLow));
+        assert!(submit(&site, 3, 2, "358").unwrap_err().ends_with("\nWelcome to the new site!"));
+
+        let request = &server.requests()[0];
+        assert!(request.starts_with("POST /2025/day/3/answer HTTP/1.0\r\n"));
+        assert!(request.ends_with("level=2&answer=357"));
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_rate_limit() {
+        let root = scratch("rate-limit");
+        let server = stub::serve(vec![(200, "a\n"), (200, "b\n")]);
+        let site = Site::new(server.url.clone(), Some("s".to_string()), &root, Duration::from_millis(300));
+
+        let start = std::time::Instant::now();
+        fetch_input(&site, 1).unwrap();
+        fetch_input(&site, 2).unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(300));
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,89 @@
+use std::fmt;
+
+use crate::parse::ParseError;
+
+// Common shape of a day: parse the raw input once, then answer both parts
+// from the parsed form. A part fails with a message when a valid input has no
+// answer, or one too big to count.
+pub trait Solution {
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Result<Answer, String>;
+    fn part2(input: &Self::Input) -> Result<Answer, String>;
+}
+
+// Puzzle answers are mostly integers of whatever width the day needed, but
+// some puzzles answer with text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Int(i128),
+    Str(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{}", n),
+            Answer::Str(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+macro_rules! answer_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::Int(n as i128)
+                }
+            }
+        )*
+    };
+}
+
+answer_from_int!(i32, i64, u32, u64, usize);
+
+// Half the u128 range is past i128, so this one can fail.
+impl TryFrom<u128> for Answer {
+    type Error = String;
+
+    fn try_from(n: u128) -> Result<Self, String> {
+        i128::try_from(n)
+            .map(Answer::Int)
+            .map_err(|_| format!("{} doesn't fit in an answer", n))
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Str(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Str(s.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_ints() {
+        assert_eq!(Answer::from(3i32), Answer::Int(3));
+        assert_eq!(Answer::from(u64::MAX), Answer::Int(u64::MAX as i128));
+        assert_eq!(Answer::from(-7i64), Answer::Int(-7));
+        assert_eq!(Answer::try_from(i128::MAX as u128), Ok(Answer::Int(i128::MAX)));
+        let too_big = "340282366920938463463374607431768211455 doesn't fit in an answer".to_string();
+        assert_eq!(Answer::try_from(u128::MAX), Err(too_big));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::from(4174379265u64).to_string(), "4174379265");
+        assert_eq!(Answer::from("ABC").to_string(), "ABC");
+    }
+}
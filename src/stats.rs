@@ -0,0 +1,170 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::time::Duration;
+
+use aoc2025::transcript::{self, Role, Transcript, Usage};
+
+// What solving one day took, from its session log.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayStats {
+    pub day: u32,
+    // From the first prompt to the last assistant message.
+    pub wall_clock: Duration,
+    // API responses; a response may hold several tool calls.
+    pub assistant_turns: usize,
+    pub tool_calls: BTreeMap<String, usize>,
+    pub usage: Usage,
+}
+
+pub fn day_stats(day: u32, transcript: &Transcript) -> DayStats {
+    let first_prompt = transcript.messages().find(|m| m.prompt().is_some()).map(|m| m.timestamp);
+    let last_answer = transcript.messages().filter(|m| m.role == Role::Assistant).map(|m| m.timestamp).max();
+    let wall_clock = match (first_prompt, last_answer) {
+        (Some(start), Some(end)) => end.since(start),
+        _ => Duration::ZERO,
+    };
+
+    let mut tool_calls = BTreeMap::new();
+    for call in transcript.tool_calls() {
+        *tool_calls.entry(call.name.to_string()).or_insert(0) += 1;
+    }
+
+    DayStats {
+        day,
+        wall_clock,
+        assistant_turns: transcript.responses().len(),
+        tool_calls,
+        usage: transcript.usage(),
+    }
+}
+
+// Every transcripts/dayNN.jsonl under `root`, by day.
+pub fn load_all(root: &Path) -> Result<Vec<DayStats>, String> {
+    let days = transcript::load_days(&root.join(transcript::DIR))?;
+    Ok(days.iter().map(|(day, transcript)| day_stats(*day, transcript)).collect())
+}
+
+// Tools used on any day, in name order; each gets a column.
+fn tool_names(stats: &[DayStats]) -> Vec<&str> {
+    let mut names: Vec<&str> = stats.iter().flat_map(|s| s.tool_calls.keys().map(String::as_str)).collect();
+    names.sort();
+    names.dedup();
+    names
+}
+
+// "1h 02m", "12m 05s" or "40s".
+pub fn format_wall_clock(d: Duration) -> String {
+    let seconds = d.as_secs();
+    if seconds >= 3600 {
+        format!("{}h {:02}m", seconds / 3600, seconds / 60 % 60)
+    } else if seconds >= 60 {
+        format!("{}m {:02}s", seconds / 60, seconds % 60)
+    } else {
+        format!("{}s", seconds)
+    }
+}
+
+pub fn to_table(stats: &[DayStats]) -> String {
+    let tools = tool_names(stats);
+    let width = |name: &str| name.len().max(4);
+
+    let mut out = format!("{:>3}  {:>8} {:>5}", "day", "time", "turns");
+    for tool in &tools {
+        out += &format!(" {:>w$}", tool, w = width(tool));
+    }
+    out += &format!(" {:>10} {:>10} {:>12} {:>12}\n", "input", "output", "cache_write", "cache_read");
+
+    for s in stats {
+        out += &format!("{:>3}  {:>8} {:>5}", s.day, format_wall_clock(s.wall_clock), s.assistant_turns);
+        for tool in &tools {
+            out += &format!(" {:>w$}", s.tool_calls.get(*tool).unwrap_or(&0), w = width(tool));
+        }
+        out += &format!(
+            " {:>10} {:>10} {:>12} {:>12}\n",
+            s.usage.input_tokens,
+            s.usage.output_tokens,
+            s.usage.cache_creation_input_tokens,
+            s.usage.cache_read_input_tokens
+        );
+    }
+    out
+}
+
+// Wall-clock time in whole seconds.
+pub fn to_csv(stats: &[DayStats]) -> String {
+    let tools = tool_names(stats);
+    let mut out = "day,wall_clock_s,assistant_turns".to_string();
+    for tool in &tools {
+        out += &format!(",{}", tool);
+    }
+    out += ",input_tokens,output_tokens,cache_creation_input_tokens,cache_read_input_tokens\n";
+
+    for s in stats {
+        out += &format!("{},{},{}", s.day, s.wall_clock.as_secs(), s.assistant_turns);
+        for tool in &tools {
+            out += &format!(",{}", s.tool_calls.get(*tool).unwrap_or(&0));
+        }
+        out += &format!(
+            ",{},{},{},{}\n",
+            s.usage.input_tokens,
+            s.usage.output_tokens,
+            s.usage.cache_creation_input_tokens,
+            s.usage.cache_read_input_tokens
+        );
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(day: u32, tools: &[(&str, usize)]) -> DayStats {
+        DayStats {
+            day,
+            wall_clock: Duration::from_secs(754),
+            assistant_turns: 12,
+            tool_calls: tools.iter().map(|(name, n)| (name.to_string(), *n)).collect(),
+            usage: Usage {
+                input_tokens: 40,
+                output_tokens: 3000,
+                cache_creation_input_tokens: 20_000,
+                cache_read_input_tokens: 150_000,
+            },
+        }
+    }
+
+    #[test]
+    fn test_format_wall_clock() {
+        assert_eq!(format_wall_clock(Duration::from_secs(40)), "40s");
+        assert_eq!(format_wall_clock(Duration::from_secs(725)), "12m 05s");
+        assert_eq!(format_wall_clock(Duration::from_secs(3720)), "1h 02m");
+    }
+
+    #[test]
+    fn test_output_formats() {
+        let days = [stats(1, &[("Read", 2), ("Bash", 5)]), stats(2, &[("TodoWrite", 1)])];
+        assert_eq!(
+            to_csv(&days),
+            "day,wall_clock_s,assistant_turns,Bash,Read,TodoWrite,input_tokens,output_tokens,cache_creation_input_tokens,cache_read_input_tokens\n\
+             1,754,12,5,2,0,40,3000,20000,150000\n\
+             2,754,12,0,0,1,40,3000,20000,150000\n"
+        );
+        assert_eq!(
+            to_table(&days[1..]),
+            "day      time turns TodoWrite      input     output  cache_write   cache_read\n  \
+             2   12m 34s    12         1         40       3000        20000       150000\n"
+        );
+    }
+
+    #[test]
+    fn test_committed_transcripts() {
+        let days = load_all(Path::new(env!("CARGO_MANIFEST_DIR"))).unwrap();
+        assert_eq!(days.len(), 10);
+        for s in &days {
+            assert!(s.wall_clock > Duration::ZERO && s.assistant_turns > 0, "day {}", s.day);
+            assert!(s.tool_calls.values().sum::<usize>() > 0, "day {}", s.day);
+            assert!(s.usage.output_tokens > 0, "day {}", s.day);
+        }
+    }
+}
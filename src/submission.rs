@@ -0,0 +1,311 @@
+use std::fmt;
+use std::io::Write;
+use std::time::Duration;
+
+use crate::parse::{self, ParseError};
+
+// Every answer sent to the site and what it said, one tab-separated line per
+// submission:
+//
+//     1733390000	3	1	357	correct
+//
+// (unix time, day, part, answer, outcome). Used to avoid sending answers the
+// site has already turned down.
+pub const PATH: &str = "submissions.log";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    RateLimited(Duration),
+    // The part was already solved, or part 1 isn't yet.
+    WrongLevel,
+}
+
+impl Outcome {
+    // Recognizes the response page to a submission.
+    pub fn classify(page: &str) -> Option<Outcome> {
+        if page.contains("That's the right answer") {
+            Some(Outcome::Correct)
+        } else if page.contains("That's not the right answer") {
+            Some(if page.contains("too high") {
+                Outcome::TooHigh
+            } else if page.contains("too low") {
+                Outcome::TooLow
+            } else {
+                Outcome::Wrong
+            })
+        } else if page.contains("You gave an answer too recently") {
+            let wait = page
+                .split_once("You have ")
+                .and_then(|(_, rest)| rest.split_once(" left to wait"))
+                .and_then(|(wait, _)| parse_wait(wait))
+                .unwrap_or(Duration::from_secs(60));
+            Some(Outcome::RateLimited(wait))
+        } else if page.contains("You don't seem to be solving the right level") {
+            Some(Outcome::WrongLevel)
+        } else {
+            None
+        }
+    }
+
+    fn parse(text: &str) -> Option<Outcome> {
+        match text {
+            "correct" => Some(Outcome::Correct),
+            "too-high" => Some(Outcome::TooHigh),
+            "too-low" => Some(Outcome::TooLow),
+            "wrong" => Some(Outcome::Wrong),
+            "wrong-level" => Some(Outcome::WrongLevel),
+            _ => {
+                let seconds = text.strip_prefix("rate-limited ")?.strip_suffix('s')?.parse().ok()?;
+                Some(Outcome::RateLimited(Duration::from_secs(seconds)))
+            }
+        }
+    }
+
+    // The form used in the log.
+    fn code(&self) -> String {
+        match self {
+            Outcome::Correct => "correct".to_string(),
+            Outcome::TooHigh => "too-high".to_string(),
+            Outcome::TooLow => "too-low".to_string(),
+            Outcome::Wrong => "wrong".to_string(),
+            Outcome::RateLimited(wait) => format!("rate-limited {}s", wait.as_secs()),
+            Outcome::WrongLevel => "wrong-level".to_string(),
+        }
+    }
+
+    // True if the site judged the answer itself.
+    fn is_verdict(&self) -> bool {
+        !matches!(self, Outcome::RateLimited(_) | Outcome::WrongLevel)
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::TooHigh => write!(f, "wrong, too high"),
+            Outcome::TooLow => write!(f, "wrong, too low"),
+            Outcome::Wrong => write!(f, "wrong"),
+            Outcome::RateLimited(wait) => write!(f, "rate limited, wait {}s", wait.as_secs()),
+            Outcome::WrongLevel => write!(f, "not the current part; already solved?"),
+        }
+    }
+}
+
+// "34s", "1m 5s" or "2h 1m" as on the site.
+fn parse_wait(text: &str) -> Option<Duration> {
+    let mut seconds = 0;
+    for part in text.split_whitespace() {
+        let unit = match part.chars().last()? {
+            'h' => 3600,
+            'm' => 60,
+            's' => 1,
+            _ => return None,
+        };
+        seconds += unit * part[..part.len() - 1].parse::<u64>().ok()?;
+    }
+    Some(Duration::from_secs(seconds))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    pub time: u64,
+    pub day: u32,
+    pub part: u8,
+    pub answer: String,
+    pub outcome: Outcome,
+}
+
+impl fmt::Display for Submission {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}\t{}\t{}\t{}\t{}",
+            self.time,
+            self.day,
+            self.part,
+            self.answer,
+            self.outcome.code()
+        )
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Log {
+    pub submissions: Vec<Submission>,
+}
+
+impl Log {
+    pub fn parse(text: &str) -> Result<Log, ParseError> {
+        let mut submissions = Vec::new();
+        for line in text.lines() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let fields: Vec<&str> = line.split('\t').collect();
+            let [time, day, part, answer, outcome] = fields[..] else {
+                return Err(ParseError::at(text, line, "time, day, part, answer and outcome separated by tabs"));
+            };
+            submissions.push(Submission {
+                time: parse::number(text, time, "unix time")?,
+                day: parse::number(text, day, "day number")?,
+                part: parse::number(text, part, "part number")?,
+                answer: answer.to_string(),
+                outcome: Outcome::parse(outcome).ok_or_else(|| ParseError::at(text, outcome, "submission outcome"))?,
+            });
+        }
+        Ok(Log { submissions })
+    }
+
+    // A missing file is an empty log.
+    pub fn load(path: &str) -> Result<Log, String> {
+        match std::fs::read_to_string(path) {
+            Ok(text) => Log::parse(&text).map_err(|e| e.in_file(path).snippet(&text)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Log::default()),
+            Err(e) => Err(format!("{}: {}", path, e)),
+        }
+    }
+
+    // Adds to the log and appends to the file.
+    pub fn append(&mut self, path: &str, submission: Submission) -> Result<(), String> {
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map_err(|e| format!("{}: {}", path, e))?;
+        writeln!(file, "{}", submission).map_err(|e| format!("{}: {}", path, e))?;
+        self.submissions.push(submission);
+        Ok(())
+    }
+
+    // Why `answer` shouldn't be sent at unix time `now`, if the log already
+    // tells us how the site will respond.
+    pub fn refusal(&self, day: u32, part: u8, answer: &str, now: u64) -> Option<String> {
+        let mut earlier = self.submissions.iter().filter(|s| s.day == day && s.part == part);
+        let number = answer.parse::<i128>().ok();
+
+        // The site's rate limit covers every puzzle, not just this one.
+        if let Some(last) = self.submissions.last()
+            && let Outcome::RateLimited(wait) = last.outcome
+            && last.time + wait.as_secs() > now
+        {
+            return Some(format!("rate limited for another {}s", last.time + wait.as_secs() - now));
+        }
+
+        earlier.find_map(|s| {
+            let bound = s.answer.parse::<i128>().ok();
+            match (s.outcome, number, bound) {
+                (Outcome::Correct, _, _) => Some(format!("already solved, the answer was {}", s.answer)),
+                (outcome, _, _) if outcome.is_verdict() && s.answer == answer => {
+                    Some(format!("{} was already submitted: {}", answer, outcome))
+                }
+                (Outcome::TooHigh, Some(n), Some(b)) if n >= b => Some(format!("{} was too high, so {} is too", b, n)),
+                (Outcome::TooLow, Some(n), Some(b)) if n <= b => Some(format!("{} was too low, so {} is too", b, n)),
+                _ => None,
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn submission(time: u64, part: u8, answer: &str, outcome: Outcome) -> Submission {
+        Submission {
+            time,
+            day: 3,
+            part,
+            answer: answer.to_string(),
+            outcome,
+        }
+    }
+
+    #[test]
+    fn test_classify() {
+        let page = |message: &str| format!("<main>\n<article><p>{}</p></article>\n</main>", message);
+        assert_eq!(
+            Outcome::classify(&page("That's the right answer!  You are one gold star closer.")),
+            Some(Outcome::Correct)
+        );
+        assert_eq!(
+            Outcome::classify(&page("That's not the right answer; your answer is too high.  If you're stuck...")),
+            Some(Outcome::TooHigh)
+        );
+        assert_eq!(
+            Outcome::classify(&page("That's not the right answer; your answer is too low.")),
+            Some(Outcome::TooLow)
+        );
+        assert_eq!(
+            Outcome::classify(&page("That's not the right answer.  If you're stuck, make sure...")),
+            Some(Outcome::Wrong)
+        );
+        assert_eq!(
+            Outcome::classify(&page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait.")),
+            Some(Outcome::RateLimited(Duration::from_secs(65)))
+        );
+        assert_eq!(
+            Outcome::classify(&page("You don't seem to be solving the right level.  Did you already complete it?")),
+            Some(Outcome::WrongLevel)
+        );
+        assert_eq!(Outcome::classify(&page("Something new")), None);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut log = Log::default();
+        let path = std::env::temp_dir().join(format!("aoc-submissions-{}.log", std::process::id()));
+        let path = path.to_str().unwrap();
+        log.append(path, submission(100, 1, "7", Outcome::TooLow)).unwrap();
+        log.append(path, submission(200, 1, "9", Outcome::RateLimited(Duration::from_secs(30)))).unwrap();
+        log.append(path, submission(300, 1, "say\"hi", Outcome::WrongLevel)).unwrap();
+
+        assert_eq!(
+            std::fs::read_to_string(path).unwrap(),
+            "100\t3\t1\t7\ttoo-low\n200\t3\t1\t9\trate-limited 30s\n300\t3\t1\tsay\"hi\twrong-level\n"
+        );
+        assert_eq!(Log::load(path), Ok(log));
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_refusal() {
+        let log = Log {
+            submissions: vec![
+                submission(100, 1, "500", Outcome::TooHigh),
+                submission(200, 1, "100", Outcome::TooLow),
+                submission(300, 1, "250", Outcome::Wrong),
+                submission(400, 1, "260", Outcome::RateLimited(Duration::from_secs(60))),
+                submission(500, 2, "1", Outcome::Correct),
+            ],
+        };
+        assert!(log.refusal(3, 1, "600", 1000).unwrap().contains("500 was too high"));
+        assert!(log.refusal(3, 1, "100", 1000).unwrap().contains("too low"));
+        assert!(log.refusal(3, 1, "250", 1000).unwrap().contains("already submitted"));
+        assert_eq!(log.refusal(3, 1, "260", 1000), None);
+        assert_eq!(log.refusal(4, 1, "600", 1000), None);
+        assert!(log.refusal(3, 2, "2", 1000).unwrap().contains("already solved"));
+    }
+
+    #[test]
+    fn test_rate_limit_refusal() {
+        let log = Log {
+            submissions: vec![submission(400, 1, "260", Outcome::RateLimited(Duration::from_secs(60)))],
+        };
+        assert_eq!(log.refusal(3, 1, "270", 430).as_deref(), Some("rate limited for another 30s"));
+        assert_eq!(log.refusal(3, 1, "270", 460), None);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            Log::parse("100\t3\t1\t7"),
+            Err(ParseError::new(1, 1, "time, day, part, answer and outcome separated by tabs"))
+        );
+        assert_eq!(Log::parse("100\t3\t1\t7\tmaybe"), Err(ParseError::new(1, 11, "submission outcome")));
+    }
+}
@@ -0,0 +1,152 @@
+// Helpers for the puzzle descriptions saved under tasks/ (see the SOP in the
+// readme).
+
+pub fn path(day: u32, part: u32) -> String {
+    format!("tasks/day{:02}-{}.md", day, part)
+}
+
+// Contents of every fenced code block, in order, each line newline-terminated
+// like a puzzle input file.
+pub fn code_blocks(markdown: &str) -> Vec<String> {
+    let mut blocks = Vec::new();
+    let mut current: Option<String> = None;
+
+    for line in markdown.lines() {
+        if line.trim_start().starts_with("```") {
+            match current.take() {
+                Some(block) => blocks.push(block),
+                None => current = Some(String::new()),
+            }
+        } else if let Some(block) = current.as_mut() {
+            block.push_str(line);
+            block.push('\n');
+        }
+    }
+
+    blocks
+}
+
+// The example input is the first code block of the part 1 description.
+pub fn example(markdown: &str) -> Option<String> {
+    code_blocks(markdown).into_iter().next()
+}
+
+// Emphasized inline code outside code blocks, in order. The converted puzzle
+// text writes these as `_40_` or _`40`_.
+pub fn emphasized_code(markdown: &str) -> Vec<String> {
+    let mut found = Vec::new();
+    let mut in_block = false;
+
+    for line in markdown.lines() {
+        if line.trim_start().starts_with("```") {
+            in_block = !in_block;
+            continue;
+        }
+        if in_block {
+            continue;
+        }
+
+        let mut offset = 0;
+        while let Some(open) = line[offset..].find('`').map(|i| offset + i) {
+            let Some(close) = line[open + 1..].find('`').map(|i| open + 1 + i) else {
+                break;
+            };
+            let code = &line[open + 1..close];
+            if code.len() > 2 && code.starts_with('_') && code.ends_with('_') {
+                found.push(code[1..code.len() - 1].to_string());
+            } else if line[..open].ends_with('_') && line[close + 1..].starts_with('_') {
+                found.push(code.to_string());
+            }
+            offset = close + 1;
+        }
+    }
+
+    found
+}
+
+// The example answer is the last emphasized code in a part's description;
+// the final paragraph is the question itself.
+pub fn answer(markdown: &str) -> Option<String> {
+    emphasized_code(markdown).pop()
+}
+
+// A day's example input with the answers both parts give for it. Part 2
+// reuses the part 1 example.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub input: String,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+pub fn load_example(day: u32) -> Result<Example, String> {
+    let path1 = path(day, 1);
+    let part1 = std::fs::read_to_string(&path1).map_err(|e| format!("{}: {}", path1, e))?;
+    let input = example(&part1).ok_or_else(|| format!("{}: no example block", path1))?;
+    // Part 2 is only saved once part 1 is solved.
+    let part2 = std::fs::read_to_string(path(day, 2)).ok();
+
+    Ok(Example {
+        input,
+        part1: answer(&part1),
+        part2: part2.as_deref().and_then(answer),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TASK: &str = "## \\--- Day 1: Test ---
+
+For example:
+
+```
+L68
+L30
+```
+
+Then:
+
+```
+ padded 
+```
+";
+
+    #[test]
+    fn test_code_blocks() {
+        assert_eq!(code_blocks(TASK), vec!["L68\nL30\n", " padded \n"]);
+    }
+
+    #[test]
+    fn test_example() {
+        assert_eq!(example(TASK).as_deref(), Some("L68\nL30\n"));
+        assert_eq!(example("no code here"), None);
+    }
+
+    #[test]
+    fn test_emphasized_code() {
+        let markdown = "Both `_11_` and `_22_` count, `33` does not.\n\n```\n`_44_`\n```\n\nSo _`3`_ are fresh.";
+        assert_eq!(emphasized_code(markdown), vec!["11", "22", "3"]);
+    }
+
+    #[test]
+    fn test_answer() {
+        let markdown = "Joltages `_98_` and `_89_`, total `_357_`.\n\n_What is the total?_";
+        assert_eq!(answer(markdown).as_deref(), Some("357"));
+        assert_eq!(answer("_no answers here_"), None);
+    }
+
+    #[test]
+    fn test_load_example() {
+        let example = load_example(8).unwrap();
+        assert!(example.input.starts_with("162,817,812\n"));
+        assert_eq!(example.part1.as_deref(), Some("40"));
+        assert_eq!(example.part2.as_deref(), Some("25272"));
+    }
+
+    #[test]
+    fn test_unterminated_block() {
+        assert!(code_blocks("```\nabc").is_empty());
+    }
+}
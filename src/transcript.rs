@@ -0,0 +1,678 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::ops::AddAssign;
+use std::path::Path;
+use std::time::Duration;
+
+use crate::json::Value;
+use crate::parse::ParseError;
+
+// The session logs in transcripts/, one JSON record per line. Messages form a
+// tree through their parent uuids; the committed logs happen to be single
+// threads, but nothing below relies on that.
+pub const DIR: &str = "transcripts";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Record {
+    Message(Message),
+    Snapshot(Snapshot),
+    // A title for the conversation ending at `leaf`.
+    Summary { summary: String, leaf: String },
+    // Queue operations and anything newer than this parser.
+    Other { kind: String, raw: Value },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Role {
+    User,
+    Assistant,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Message {
+    pub uuid: String,
+    pub parent: Option<String>,
+    pub session_id: String,
+    // The client's working directory; tool inputs use absolute paths.
+    pub cwd: Option<String>,
+    pub timestamp: Timestamp,
+    pub role: Role,
+    // Injected by the client rather than typed.
+    pub is_meta: bool,
+    pub is_sidechain: bool,
+    // The API message id. A response with several content blocks is logged
+    // as one record per block, all with the same id.
+    pub id: Option<String>,
+    pub model: Option<String>,
+    pub content: Vec<Block>,
+    pub usage: Option<Usage>,
+    // The client's structured view of a tool result (patches, stdout...).
+    pub tool_use_result: Option<Value>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Block {
+    Text(String),
+    Thinking(String),
+    ToolUse { id: String, name: String, input: Value },
+    ToolResult { tool_use_id: String, content: String, is_error: bool },
+    Other(Value),
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Usage {
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+    pub cache_creation_input_tokens: u64,
+    pub cache_read_input_tokens: u64,
+}
+
+// The state of tracked files as of message `message_id`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Snapshot {
+    pub message_id: String,
+    pub timestamp: Timestamp,
+    pub files: Vec<TrackedFile>,
+    pub is_update: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrackedFile {
+    pub path: String,
+    // Name of the copy under the client's file-history directory; None if
+    // the file didn't exist yet.
+    pub backup: Option<String>,
+    pub version: u32,
+    pub backup_time: Option<Timestamp>,
+}
+
+// Milliseconds since the unix epoch, UTC.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Timestamp(pub u64);
+
+impl Timestamp {
+    // "2025-12-01T10:24:07.204Z".
+    pub fn parse(text: &str) -> Option<Timestamp> {
+        let (date, time) = text.strip_suffix('Z')?.split_once('T')?;
+        let mut date = date.splitn(3, '-').map(|n| n.parse::<i64>().ok());
+        let (year, month, day) = (date.next()??, date.next()??, date.next()??);
+        let (hms, millis) = time.split_once('.').unwrap_or((time, "0"));
+        let mut hms = hms.splitn(3, ':').map(|n| n.parse::<u64>().ok());
+        let (hour, minute, second) = (hms.next()??, hms.next()??, hms.next()??);
+        let millis: u64 = format!("{:0<3}", millis).get(..3)?.parse().ok()?;
+        if !(1..=12).contains(&month) || !(1..=31).contains(&day) || hour > 23 || minute > 59 || second > 60 {
+            return None;
+        }
+        let days = u64::try_from(days_from_civil(year, month, day)).ok()?;
+        Some(Timestamp(((days * 24 + hour) * 60 + minute) * 60_000 + second * 1000 + millis))
+    }
+
+    // Time from `earlier` to this, or zero if `earlier` isn't.
+    pub fn since(&self, earlier: Timestamp) -> Duration {
+        Duration::from_millis(self.0.saturating_sub(earlier.0))
+    }
+}
+
+impl fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (days, millis) = (self.0 / 86_400_000, self.0 % 86_400_000);
+        let (year, month, day) = civil_from_days(days as i64);
+        let seconds = millis / 1000;
+        write!(
+            f,
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}Z",
+            year,
+            month,
+            day,
+            seconds / 3600,
+            seconds / 60 % 60,
+            seconds % 60,
+            millis % 1000
+        )
+    }
+}
+
+// Howard Hinnant's algorithms for the proleptic Gregorian calendar.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    (year_of_era + era * 400 + i64::from(month <= 2), month, day)
+}
+
+impl AddAssign for Usage {
+    fn add_assign(&mut self, other: Usage) {
+        self.input_tokens += other.input_tokens;
+        self.output_tokens += other.output_tokens;
+        self.cache_creation_input_tokens += other.cache_creation_input_tokens;
+        self.cache_read_input_tokens += other.cache_read_input_tokens;
+    }
+}
+
+impl Message {
+    pub fn text(&self) -> String {
+        let texts: Vec<&str> = self
+            .content
+            .iter()
+            .filter_map(|block| match block {
+                Block::Text(text) => Some(text.as_str()),
+                _ => None,
+            })
+            .collect();
+        texts.join("\n")
+    }
+
+    // The text of something the user typed, as opposed to tool results,
+    // client notices, slash commands and their output.
+    pub fn prompt(&self) -> Option<String> {
+        if self.role != Role::User || self.is_meta {
+            return None;
+        }
+        let text = self.text();
+        let generated = text.starts_with("<command-")
+            || text.starts_with("<local-command-")
+            || text.starts_with("[Request interrupted");
+        (!text.trim().is_empty() && !generated).then_some(text)
+    }
+
+    pub fn tool_uses(&self) -> impl Iterator<Item = (&str, &str, &Value)> {
+        self.content.iter().filter_map(|block| match block {
+            Block::ToolUse { id, name, input } => Some((id.as_str(), name.as_str(), input)),
+            _ => None,
+        })
+    }
+}
+
+// A tool invocation and, unless the session ended first, its result.
+#[derive(Debug, Clone, Copy)]
+pub struct ToolCall<'a> {
+    pub id: &'a str,
+    pub name: &'a str,
+    pub input: &'a Value,
+    pub message: &'a Message,
+    pub result: Option<ToolResult<'a>>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ToolResult<'a> {
+    pub tool_use_id: &'a str,
+    pub content: &'a str,
+    pub is_error: bool,
+    pub message: &'a Message,
+}
+
+// A prompt and everything that followed it up to the next prompt.
+#[derive(Debug, Clone)]
+pub struct Turn<'a> {
+    pub prompt: &'a Message,
+    pub replies: Vec<&'a Message>,
+}
+
+impl<'a> Turn<'a> {
+    pub fn messages(&self) -> impl Iterator<Item = &'a Message> + '_ {
+        std::iter::once(self.prompt).chain(self.replies.iter().copied())
+    }
+
+    pub fn responses(&self) -> Vec<&'a Message> {
+        responses(self.messages())
+    }
+
+    pub fn usage(&self) -> Usage {
+        usage(&self.responses())
+    }
+}
+
+// One message per API response: the last record logged for its id, which
+// carries the final output token count. In order of first appearance.
+fn responses<'a>(messages: impl Iterator<Item = &'a Message>) -> Vec<&'a Message> {
+    let mut responses: Vec<&Message> = Vec::new();
+    let mut seen: HashMap<&str, usize> = HashMap::new();
+    for message in messages.filter(|m| m.role == Role::Assistant) {
+        match message.id.as_deref().and_then(|id| seen.get(id)) {
+            Some(&i) => responses[i] = message,
+            None => {
+                if let Some(id) = message.id.as_deref() {
+                    seen.insert(id, responses.len());
+                }
+                responses.push(message);
+            }
+        }
+    }
+    responses
+}
+
+// Summed over responses, so split responses count once.
+fn usage(responses: &[&Message]) -> Usage {
+    let mut total = Usage::default();
+    for response in responses {
+        total += response.usage.unwrap_or_default();
+    }
+    total
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Transcript {
+    pub records: Vec<Record>,
+    // uuid -> index into records.
+    index: HashMap<String, usize>,
+}
+
+impl Transcript {
+    pub fn parse(text: &str) -> Result<Transcript, ParseError> {
+        let mut transcript = Transcript::default();
+        for (number, line) in text.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let value = Value::parse(line).map_err(|e| ParseError { line: number + 1, ..e })?;
+            let record = Record::from_json(value).map_err(|expected| ParseError::at(text, line, expected))?;
+            if let Record::Message(message) = &record {
+                transcript.index.insert(message.uuid.clone(), transcript.records.len());
+            }
+            transcript.records.push(record);
+        }
+        Ok(transcript)
+    }
+
+    pub fn load(path: &str) -> Result<Transcript, String> {
+        let text = std::fs::read_to_string(path).map_err(|e| format!("{}: {}", path, e))?;
+        Transcript::parse(&text).map_err(|e| e.in_file(path).to_string())
+    }
+
+    // In file order.
+    pub fn messages(&self) -> impl Iterator<Item = &Message> {
+        self.records.iter().filter_map(|record| match record {
+            Record::Message(message) => Some(message),
+            _ => None,
+        })
+    }
+
+    pub fn responses(&self) -> Vec<&Message> {
+        responses(self.messages())
+    }
+
+    pub fn usage(&self) -> Usage {
+        usage(&self.responses())
+    }
+
+    // The client's latest summary of this conversation. Logs also carry
+    // summaries of earlier sessions, which point at leaves not in this file.
+    pub fn title(&self) -> Option<&str> {
+        self.records.iter().rev().find_map(|record| match record {
+            Record::Summary { summary, leaf } if self.get(leaf).is_some() => Some(summary.as_str()),
+            _ => None,
+        })
+    }
+
+    pub fn snapshots(&self) -> impl Iterator<Item = &Snapshot> {
+        self.records.iter().filter_map(|record| match record {
+            Record::Snapshot(snapshot) => Some(snapshot),
+            _ => None,
+        })
+    }
+
+    pub fn get(&self, uuid: &str) -> Option<&Message> {
+        match self.records.get(*self.index.get(uuid)?)? {
+            Record::Message(message) => Some(message),
+            _ => None,
+        }
+    }
+
+    pub fn parent(&self, message: &Message) -> Option<&Message> {
+        self.get(message.parent.as_deref()?)
+    }
+
+    pub fn children<'a>(&'a self, uuid: &'a str) -> impl Iterator<Item = &'a Message> {
+        self.messages().filter(move |m| m.parent.as_deref() == Some(uuid))
+    }
+
+    // Messages whose parent is missing from the file.
+    pub fn roots(&self) -> impl Iterator<Item = &Message> {
+        self.messages().filter(|m| self.parent(m).is_none())
+    }
+
+    // The path from the root down to `leaf`. Parents that loop back, as in a
+    // hand-edited log, end the path at the first message seen twice.
+    pub fn thread(&self, leaf: &str) -> Vec<&Message> {
+        let mut seen = HashSet::new();
+        let mut thread: Vec<&Message> = std::iter::successors(self.get(leaf), |m| self.parent(m))
+            .take_while(|m| seen.insert(m.uuid.as_str()))
+            .collect();
+        thread.reverse();
+        thread
+    }
+
+    // The thread ending at the last message logged outside a sidechain, which
+    // skips abandoned branches.
+    pub fn main_thread(&self) -> Vec<&Message> {
+        match self.messages().filter(|m| !m.is_sidechain).last() {
+            Some(leaf) => self.thread(&leaf.uuid),
+            None => Vec::new(),
+        }
+    }
+
+    // Along the main thread; messages before the first prompt belong to none.
+    pub fn turns(&self) -> Vec<Turn<'_>> {
+        let mut turns: Vec<Turn> = Vec::new();
+        for message in self.main_thread() {
+            if message.prompt().is_some() {
+                turns.push(Turn {
+                    prompt: message,
+                    replies: Vec::new(),
+                });
+            } else if let Some(turn) = turns.last_mut() {
+                turn.replies.push(message);
+            }
+        }
+        turns
+    }
+
+    // In file order.
+    pub fn tool_results(&self) -> impl Iterator<Item = ToolResult<'_>> {
+        self.messages().flat_map(|message| {
+            message.content.iter().filter_map(move |block| match block {
+                Block::ToolResult { tool_use_id, content, is_error } => Some(ToolResult {
+                    tool_use_id,
+                    content,
+                    is_error: *is_error,
+                    message,
+                }),
+                _ => None,
+            })
+        })
+    }
+
+    // In file order, each paired with its result.
+    pub fn tool_calls(&self) -> impl Iterator<Item = ToolCall<'_>> {
+        let results: HashMap<&str, ToolResult> = self.tool_results().map(|r| (r.tool_use_id, r)).collect();
+        let mut calls = Vec::new();
+        for message in self.messages() {
+            for (id, name, input) in message.tool_uses() {
+                calls.push(ToolCall {
+                    id,
+                    name,
+                    input,
+                    message,
+                    result: results.get(id).copied(),
+                });
+            }
+        }
+        calls.into_iter()
+    }
+}
+
+// Every dayNN.jsonl in `dir`, by day.
+pub fn load_days(dir: &Path) -> Result<Vec<(u32, Transcript)>, String> {
+    let entries = std::fs::read_dir(dir).map_err(|e| format!("{}: {}", dir.display(), e))?;
+    let mut days = Vec::new();
+    for entry in entries {
+        let path = entry.map_err(|e| format!("{}: {}", dir.display(), e))?.path();
+        let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+        let Some(Ok(day)) = name.strip_prefix("day").and_then(|n| n.strip_suffix(".jsonl")).map(str::parse) else {
+            continue;
+        };
+        days.push((day, Transcript::load(&path.to_string_lossy())?));
+    }
+    days.sort_by_key(|(day, _)| *day);
+    Ok(days)
+}
+
+impl Record {
+    // The error is what was expected and missing.
+    fn from_json(value: Value) -> Result<Record, String> {
+        let kind = value.get("type").as_str().ok_or("a record with a type")?;
+        match kind {
+            "user" | "assistant" => Ok(Record::Message(Message::from_json(&value)?)),
+            "file-history-snapshot" => Ok(Record::Snapshot(Snapshot::from_json(&value)?)),
+            "summary" => Ok(Record::Summary {
+                summary: string(&value, "summary")?,
+                leaf: string(&value, "leafUuid")?,
+            }),
+            _ => Ok(Record::Other {
+                kind: kind.to_string(),
+                raw: value,
+            }),
+        }
+    }
+}
+
+fn string(value: &Value, key: &str) -> Result<String, String> {
+    value.get(key).as_str().map(str::to_string).ok_or_else(|| format!("a string '{}'", key))
+}
+
+fn timestamp(value: &Value) -> Result<Timestamp, String> {
+    value.get("timestamp").as_str().and_then(Timestamp::parse).ok_or_else(|| "an ISO 8601 'timestamp'".to_string())
+}
+
+impl Message {
+    fn from_json(value: &Value) -> Result<Message, String> {
+        let role = match value.get("type").as_str() {
+            Some("assistant") => Role::Assistant,
+            _ => Role::User,
+        };
+        let message = value.get("message");
+        let content = match message.get("content") {
+            Value::String(text) => vec![Block::Text(text.clone())],
+            Value::Array(blocks) => blocks.iter().map(Block::from_json).collect::<Result<_, _>>()?,
+            _ => return Err("message content".to_string()),
+        };
+        let usage = message.get("usage");
+        let tokens = |key| usage.get(key).as_u64().unwrap_or(0);
+        Ok(Message {
+            uuid: string(value, "uuid")?,
+            parent: value.get("parentUuid").as_str().map(str::to_string),
+            session_id: string(value, "sessionId")?,
+            cwd: value.get("cwd").as_str().map(str::to_string),
+            timestamp: timestamp(value)?,
+            role,
+            is_meta: value.get("isMeta").as_bool().unwrap_or(false),
+            is_sidechain: value.get("isSidechain").as_bool().unwrap_or(false),
+            id: message.get("id").as_str().map(str::to_string),
+            model: message.get("model").as_str().map(str::to_string),
+            content,
+            usage: (!usage.is_null()).then(|| Usage {
+                input_tokens: tokens("input_tokens"),
+                output_tokens: tokens("output_tokens"),
+                cache_creation_input_tokens: tokens("cache_creation_input_tokens"),
+                cache_read_input_tokens: tokens("cache_read_input_tokens"),
+            }),
+            tool_use_result: Some(value.get("toolUseResult").clone()).filter(|v| !v.is_null()),
+        })
+    }
+}
+
+impl Block {
+    fn from_json(value: &Value) -> Result<Block, String> {
+        Ok(match value.get("type").as_str() {
+            Some("text") => Block::Text(string(value, "text")?),
+            Some("thinking") => Block::Thinking(string(value, "thinking")?),
+            Some("tool_use") => Block::ToolUse {
+                id: string(value, "id")?,
+                name: string(value, "name")?,
+                input: value.get("input").clone(),
+            },
+            Some("tool_result") => Block::ToolResult {
+                tool_use_id: string(value, "tool_use_id")?,
+                // Either a string or a list of text blocks.
+                content: match value.get("content") {
+                    Value::String(text) => text.clone(),
+                    content => {
+                        let texts: Vec<&str> = content.as_array().iter().filter_map(|b| b.get("text").as_str()).collect();
+                        texts.join("\n")
+                    }
+                },
+                is_error: value.get("is_error").as_bool().unwrap_or(false),
+            },
+            _ => Block::Other(value.clone()),
+        })
+    }
+}
+
+impl Snapshot {
+    fn from_json(value: &Value) -> Result<Snapshot, String> {
+        let snapshot = value.get("snapshot");
+        let mut files = Vec::new();
+        for (path, backup) in snapshot.get("trackedFileBackups").as_object() {
+            files.push(TrackedFile {
+                path: path.clone(),
+                backup: backup.get("backupFileName").as_str().map(str::to_string),
+                version: backup.get("version").as_u64().ok_or("a backup 'version'")? as u32,
+                backup_time: backup.get("backupTime").as_str().and_then(Timestamp::parse),
+            });
+        }
+        Ok(Snapshot {
+            message_id: string(value, "messageId")?,
+            timestamp: timestamp(snapshot)?,
+            files,
+            is_update: value.get("isSnapshotUpdate").as_bool().unwrap_or(false),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SESSION: &str = r#"{"type":"file-history-snapshot","messageId":"u1","snapshot":{"messageId":"u1","trackedFileBackups":{},"timestamp":"2025-12-01T10:00:00.000Z"},"isSnapshotUpdate":false}
+{"parentUuid":null,"isSidechain":false,"sessionId":"s","type":"user","message":{"role":"user","content":"Caveat: local commands"},"isMeta":true,"uuid":"u0","timestamp":"2025-12-01T10:00:00.000Z"}
+{"parentUuid":"u0","isSidechain":false,"sessionId":"s","type":"user","message":{"role":"user","content":"solve day 1"},"uuid":"u1","timestamp":"2025-12-01T10:00:01.000Z"}
+{"parentUuid":"u1","isSidechain":false,"sessionId":"s","type":"assistant","message":{"model":"m","id":"msg1","role":"assistant","content":[{"type":"thinking","thinking":"hmm"}],"usage":{"input_tokens":10,"output_tokens":5,"cache_creation_input_tokens":100,"cache_read_input_tokens":0}},"uuid":"a1","timestamp":"2025-12-01T10:00:02.000Z"}
+{"parentUuid":"a1","isSidechain":false,"sessionId":"s","type":"assistant","message":{"model":"m","id":"msg1","role":"assistant","content":[{"type":"tool_use","id":"t1","name":"Read","input":{"file_path":"src/day01.rs"}}],"usage":{"input_tokens":10,"output_tokens":40,"cache_creation_input_tokens":100,"cache_read_input_tokens":0}},"uuid":"a2","timestamp":"2025-12-01T10:00:03.000Z"}
+{"parentUuid":"a2","isSidechain":false,"sessionId":"s","type":"user","message":{"role":"user","content":[{"tool_use_id":"t1","type":"tool_result","content":"fn main() {}"}]},"uuid":"u2","timestamp":"2025-12-01T10:00:04.000Z","toolUseResult":{"type":"text"}}
+{"parentUuid":"u2","isSidechain":false,"sessionId":"s","type":"assistant","message":{"model":"m","id":"msg2","role":"assistant","content":[{"type":"tool_use","id":"t2","name":"Bash","input":{"command":"cargo run"}}]},"uuid":"a3","timestamp":"2025-12-01T10:00:05.000Z"}
+{"parentUuid":"a3","isSidechain":false,"sessionId":"s","type":"user","message":{"role":"user","content":[{"tool_use_id":"t2","type":"tool_result","content":[{"type":"text","text":"boom"}],"is_error":true}]},"uuid":"u3","timestamp":"2025-12-01T10:00:06.000Z"}
+{"type":"summary","summary":"Day 1","leafUuid":"a4"}
+{"parentUuid":"u3","isSidechain":false,"sessionId":"s","type":"user","message":{"role":"user","content":"now part 2"},"uuid":"u4","timestamp":"2025-12-01T10:01:00.000Z"}
+{"parentUuid":"u4","isSidechain":false,"sessionId":"s","type":"assistant","message":{"model":"m","id":"msg3","role":"assistant","content":[{"type":"text","text":"Done."}]},"uuid":"a4","timestamp":"2025-12-01T10:01:30.500Z"}
+{"parentUuid":"u3","isSidechain":false,"sessionId":"s","type":"user","message":{"role":"user","content":"abandoned"},"uuid":"x1","timestamp":"2025-12-01T10:00:59.000Z"}
+{"type":"queue-operation","operation":"enqueue","timestamp":"2025-12-01T10:02:00.000Z","sessionId":"s"}
+"#;
+
+    #[test]
+    fn test_parse() {
+        let transcript = Transcript::parse(SESSION).unwrap();
+        assert_eq!(transcript.records.len(), 13);
+        assert!(matches!(&transcript.records[8], Record::Summary { summary, leaf } if summary == "Day 1" && leaf == "a4"));
+        assert!(matches!(&transcript.records[12], Record::Other { kind, .. } if kind == "queue-operation"));
+        assert_eq!(transcript.snapshots().next().unwrap().message_id, "u1");
+        assert_eq!(transcript.title(), Some("Day 1"));
+
+        let a2 = transcript.get("a2").unwrap();
+        assert_eq!(a2.role, Role::Assistant);
+        assert_eq!(a2.id.as_deref(), Some("msg1"));
+        assert_eq!(a2.usage.unwrap().output_tokens, 40);
+        assert_eq!(transcript.get("u2").unwrap().tool_use_result, Some(Value::parse(r#"{"type":"text"}"#).unwrap()));
+        assert!(transcript.get("u0").unwrap().is_meta);
+        assert_eq!(transcript.get("u1").unwrap().prompt().as_deref(), Some("solve day 1"));
+        assert_eq!(transcript.get("u2").unwrap().prompt(), None);
+    }
+
+    #[test]
+    fn test_tree() {
+        let transcript = Transcript::parse(SESSION).unwrap();
+        let uuids = |messages: Vec<&Message>| messages.iter().map(|m| m.uuid.clone()).collect::<Vec<_>>();
+        assert_eq!(uuids(transcript.roots().collect()), ["u0"]);
+        assert_eq!(uuids(transcript.children("u3").collect()), ["u4", "x1"]);
+        assert_eq!(transcript.parent(transcript.get("a1").unwrap()).unwrap().uuid, "u1");
+        assert_eq!(uuids(transcript.thread("a2")), ["u0", "u1", "a1", "a2"]);
+        // The last message is on the abandoned branch.
+        assert_eq!(uuids(transcript.main_thread()).last().unwrap(), "x1");
+
+        let cycle = [("c1", "c2"), ("c2", "c1")]
+            .map(|(uuid, parent)| {
+                format!(r#"{{"parentUuid":"{}","isSidechain":false,"sessionId":"s","type":"user","message":{{"role":"user","content":"hi"}},"uuid":"{}","timestamp":"2025-12-01T10:00:00.000Z"}}"#, parent, uuid)
+            })
+            .join("\n");
+        let transcript = Transcript::parse(&cycle).unwrap();
+        assert_eq!(uuids(transcript.thread("c2")), ["c1", "c2"]);
+        assert_eq!(uuids(transcript.main_thread()), ["c1", "c2"]);
+    }
+
+    #[test]
+    fn test_turns() {
+        let transcript = Transcript::parse(SESSION).unwrap();
+        let turns = transcript.turns();
+        assert_eq!(turns.len(), 2);
+        assert_eq!(turns[0].prompt.uuid, "u1");
+        assert_eq!(turns[0].replies.len(), 5);
+        assert_eq!(turns[1].prompt.prompt().as_deref(), Some("abandoned"));
+        assert_eq!(turns[0].messages().count(), 6);
+        assert_eq!(turns[0].responses().len(), 2);
+        assert_eq!(turns[0].usage().output_tokens, 40);
+    }
+
+    #[test]
+    fn test_tool_calls() {
+        let transcript = Transcript::parse(SESSION).unwrap();
+        let calls: Vec<ToolCall> = transcript.tool_calls().collect();
+        assert_eq!(calls.len(), 2);
+        assert_eq!((calls[0].name, calls[0].input.get("file_path").as_str()), ("Read", Some("src/day01.rs")));
+        assert_eq!(calls[0].result.unwrap().content, "fn main() {}");
+        assert_eq!(calls[0].result.unwrap().message.uuid, "u2");
+        assert!(calls[1].result.unwrap().is_error);
+        assert_eq!(calls[1].result.unwrap().content, "boom");
+        assert_eq!(transcript.tool_results().count(), 2);
+    }
+
+    #[test]
+    fn test_usage() {
+        let transcript = Transcript::parse(SESSION).unwrap();
+        let responses: Vec<&str> = transcript.responses().iter().map(|m| m.uuid.as_str()).collect();
+        assert_eq!(responses, ["a2", "a3", "a4"]);
+        assert_eq!(
+            transcript.usage(),
+            Usage {
+                input_tokens: 10,
+                output_tokens: 40,
+                cache_creation_input_tokens: 100,
+                cache_read_input_tokens: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn test_timestamp() {
+        let time = Timestamp::parse("2025-12-01T10:24:07.204Z").unwrap();
+        assert_eq!(time, Timestamp(1_764_584_647_204));
+        assert_eq!(time.to_string(), "2025-12-01T10:24:07.204Z");
+        assert_eq!(Timestamp::parse("1970-01-01T00:00:00Z"), Some(Timestamp(0)));
+        assert_eq!(Timestamp::parse("2024-02-29T00:00:00.5Z").unwrap().to_string(), "2024-02-29T00:00:00.500Z");
+        assert_eq!(Timestamp::parse("2025-13-01T00:00:00Z"), None);
+        assert_eq!(time.since(Timestamp(time.0 - 1500)), Duration::from_millis(1500));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            Transcript::parse("{\"type\":\"summary\"}\n{\"type\":\"summary\",\"summary\":\"x\",\"leafUuid\":\"y\"}"),
+            Err(ParseError::new(1, 1, "a string 'summary'"))
+        );
+        assert_eq!(Transcript::parse("{}\n{\"type\" 1}"), Err(ParseError::new(1, 1, "a record with a type")));
+        assert_eq!(Transcript::parse("{\"type\":\"x\"}\n{\"type\" 1}"), Err(ParseError::new(2, 9, "':'")));
+    }
+
+    #[test]
+    fn test_committed_transcripts() {
+        let days = load_days(&Path::new(env!("CARGO_MANIFEST_DIR")).join(DIR)).unwrap();
+        assert_eq!(days.iter().map(|(day, _)| *day).collect::<Vec<_>>(), (1..=10).collect::<Vec<_>>());
+        for (day, transcript) in &days {
+            assert_eq!(transcript.roots().count(), 1, "day {}", day);
+            assert!(!transcript.turns().is_empty(), "day {}", day);
+            assert!(transcript.tool_calls().all(|call| call.result.is_some()), "day {}", day);
+        }
+    }
+}
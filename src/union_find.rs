@@ -0,0 +1,136 @@
+use std::collections::BTreeMap;
+
+// Disjoint sets over the elements 0..n, merged by size with path compression.
+#[derive(Debug, Clone)]
+pub struct UnionFind {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    components: usize,
+}
+
+impl UnionFind {
+    // n singleton sets.
+    pub fn new(n: usize) -> Self {
+        UnionFind {
+            parent: (0..n).collect(),
+            size: vec![1; n],
+            components: n,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    // The representative of x's set. Iterative, so long chains can't
+    // overflow the stack.
+    pub fn find(&mut self, x: usize) -> usize {
+        let root = self.root(x);
+        let mut node = x;
+        while node != root {
+            node = std::mem::replace(&mut self.parent[node], root);
+        }
+        root
+    }
+
+    // find() without the path compression, for &self callers.
+    fn root(&self, mut x: usize) -> usize {
+        while self.parent[x] != x {
+            x = self.parent[x];
+        }
+        x
+    }
+
+    // Merges the sets holding x and y. False if they were already one set.
+    pub fn union(&mut self, x: usize, y: usize) -> bool {
+        let (mut a, mut b) = (self.find(x), self.find(y));
+        if a == b {
+            return false;
+        }
+        if self.size[a] < self.size[b] {
+            std::mem::swap(&mut a, &mut b);
+        }
+        self.parent[b] = a;
+        self.size[a] += self.size[b];
+        self.components -= 1;
+        true
+    }
+
+    pub fn connected(&mut self, x: usize, y: usize) -> bool {
+        self.find(x) == self.find(y)
+    }
+
+    pub fn component_count(&self) -> usize {
+        self.components
+    }
+
+    // Size of the set holding x.
+    pub fn component_size(&self, x: usize) -> usize {
+        self.size[self.root(x)]
+    }
+
+    // Every set's members in ascending order, sets ordered by their smallest
+    // member.
+    pub fn components(&self) -> impl Iterator<Item = Vec<usize>> + use<> {
+        let mut by_root: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+        let mut order = Vec::new();
+        for x in 0..self.len() {
+            let root = self.root(x);
+            let members = by_root.entry(root).or_default();
+            if members.is_empty() {
+                order.push(root);
+            }
+            members.push(x);
+        }
+        order.into_iter().map(move |root| by_root.remove(&root).unwrap())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_union() {
+        let mut uf = UnionFind::new(5);
+        assert_eq!(uf.component_count(), 5);
+        assert!(uf.union(0, 1));
+        assert!(uf.union(3, 1));
+        assert!(!uf.union(0, 3));
+        assert!(uf.connected(0, 3));
+        assert!(!uf.connected(0, 2));
+        assert_eq!(uf.component_count(), 3);
+        assert_eq!(uf.component_size(3), 3);
+        assert_eq!(uf.component_size(4), 1);
+    }
+
+    #[test]
+    fn test_components() {
+        let mut uf = UnionFind::new(6);
+        uf.union(4, 1);
+        uf.union(5, 0);
+        uf.union(1, 2);
+        let components: Vec<Vec<usize>> = uf.components().collect();
+        assert_eq!(components, [vec![0, 5], vec![1, 2, 4], vec![3]]);
+        assert!(UnionFind::new(0).components().next().is_none());
+    }
+
+    #[test]
+    fn test_long_chain() {
+        // Union by size keeps trees shallow, so link roots directly to build
+        // the worst case a recursive find would choke on.
+        let n = 1_000_000;
+        let mut uf = UnionFind::new(n);
+        for x in 1..n {
+            uf.parent[x] = x - 1;
+        }
+        uf.size[0] = n;
+        assert_eq!(uf.find(n - 1), 0);
+        assert_eq!(uf.parent[n - 1], 0);
+        assert_eq!(uf.component_size(n / 2), n);
+    }
+}
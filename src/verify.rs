@@ -0,0 +1,75 @@
+use aoc2025::ledger::{self, Ledger};
+
+use crate::check::{self, Check};
+use crate::days::Day;
+
+// A day's real input run against the answers recorded for it.
+pub struct Verified {
+    pub hash: String,
+    pub recorded: bool,
+    pub checks: Vec<Check>,
+}
+
+impl Verified {
+    // A part that panics or fails counts against a recorded day even when
+    // the ledger has no answer for it.
+    pub fn status(&self) -> &'static str {
+        if !self.recorded {
+            "unrecorded"
+        } else if self.checks.iter().any(|c| c.actual.is_err() || (c.expected.is_some() && !c.passed())) {
+            "MISMATCH"
+        } else {
+            "ok"
+        }
+    }
+}
+
+// None when the day has no input in data/.
+pub fn verify_day(day: &Day, ledger: &Ledger) -> Option<Verified> {
+    let path = day.input_path();
+    let input = std::fs::read_to_string(&path).ok()?;
+    let hash = ledger::input_hash(&input);
+    let entry = ledger.get(day.number, &hash);
+    let expected = [1, 2].map(|part| entry.and_then(|e| e.part(part)).map(str::to_string));
+
+    Some(Verified {
+        recorded: entry.is_some(),
+        checks: check::check(day, &input, &path, expected),
+        hash,
+    })
+}
+
+// Table cell for one part: the answer, plus what was expected if it differs.
+pub fn cell(check: &Check) -> String {
+    match &check.expected {
+        Some(expected) if !check.passed() => format!("{} (expected {})", check.outcome(), expected),
+        _ => check.outcome().to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn verified(expected: Option<&str>, actual: Result<&str, &str>) -> Verified {
+        Verified {
+            hash: "h".to_string(),
+            recorded: true,
+            checks: vec![Check {
+                day: 1,
+                part: 1,
+                expected: expected.map(str::to_string),
+                actual: actual.map(str::to_string).map_err(str::to_string),
+            }],
+        }
+    }
+
+    #[test]
+    fn test_status() {
+        assert_eq!(verified(Some("3"), Ok("3")).status(), "ok");
+        assert_eq!(verified(Some("3"), Ok("4")).status(), "MISMATCH");
+        assert_eq!(verified(None, Ok("4")).status(), "ok");
+        assert_eq!(verified(None, Err("panicked: boom")).status(), "MISMATCH");
+        assert_eq!(verified(Some("3"), Err("panicked: boom")).status(), "MISMATCH");
+    }
+}
@@ -0,0 +1,22 @@
+// The solvers as another crate sees them, through the library alone.
+use aoc2025::day03::{self, max_joltage_k};
+use aoc2025::day10::{self, Machine};
+use aoc2025::solution::Solution;
+
+#[test]
+fn test_helpers() {
+    assert_eq!(max_joltage_k(&[8, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 9], 12), 811111111119);
+    assert_eq!(day03::max_joltage(&[8, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 9]), 89);
+
+    let machines: Vec<Machine> = day10::parse("[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}").unwrap();
+    assert_eq!(machines[0].buttons.len(), 6);
+    assert_eq!(day10::solve_machine(&machines[0].lights, &machines[0].buttons), Some(2));
+    assert_eq!(day10::solve_joltage(&machines[0].buttons, &machines[0].joltage), Some(10));
+}
+
+#[test]
+fn test_solution_trait() {
+    let banks = day03::Day03::parse("987654321111111\n811111111111119\n").unwrap();
+    assert_eq!(day03::Day03::part1(&banks).unwrap().to_string(), "187");
+    assert_eq!(day03::Day03::part2(&banks).unwrap().to_string(), "1798765432230");
+}